@@ -1,8 +1,9 @@
-from typing import Callable, List
+from typing import Callable, List, Optional
 
 from metaheurustics.typing import (
     IndividualProtocol,
     MutatorProtocol,
+    ObserverProtocol,
     RecombinatorProtocol,
 )
 
@@ -68,5 +69,10 @@ class Solver:
         initializer: Callable[[SmallRng, int], IndividualProtocol],
     ) -> None:
         pass
-    def solve(self, population_size: int, number_iterations: int) -> List[object]:
+    def solve(
+        self,
+        population_size: int,
+        number_iterations: int,
+        observer: Optional[ObserverProtocol] = None,
+    ) -> List[object]:
         pass
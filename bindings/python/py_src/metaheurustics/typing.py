@@ -79,3 +79,94 @@ class RecombinatorProtocol(Protocol):
         Returns:
             List[IndividualProtocol]: The recombined individuals.
         """
+
+
+class ObserverProtocol(Protocol):
+    """A protocol describing the hooks an observer of a solver may implement.
+
+    Every hook is optional, the solver only calls the methods that are defined.
+
+    Args:
+        Protocol (_type_): _description_
+    """
+
+    def on_initialization(
+        self,
+        population: List[IndividualProtocol],
+        generation: int,
+        evaluations: int,
+    ) -> None:
+        """Called after the initial population has been evaluated.
+
+        Args:
+            population (List[IndividualProtocol]): The current population.
+            generation (int): The number of completed generations.
+            evaluations (int): The number of fitness evaluations used.
+        """
+
+    def on_parent_selection(
+        self,
+        population: List[IndividualProtocol],
+        mating_pool: List[IndividualProtocol],
+        generation: int,
+        evaluations: int,
+    ) -> None:
+        """Called after the parents of a generation have been selected.
+
+        Args:
+            population (List[IndividualProtocol]): The current population.
+            mating_pool (List[IndividualProtocol]): The selected parents.
+            generation (int): The number of completed generations.
+            evaluations (int): The number of fitness evaluations used.
+        """
+
+    def on_offspring_evaluation(
+        self,
+        population: List[IndividualProtocol],
+        offspring: List[IndividualProtocol],
+        generation: int,
+        evaluations: int,
+    ) -> None:
+        """Called after the offspring of a generation have been evaluated.
+
+        Args:
+            population (List[IndividualProtocol]): The current population.
+            offspring (List[IndividualProtocol]): The evaluated offspring.
+            generation (int): The number of completed generations.
+            evaluations (int): The number of fitness evaluations used.
+        """
+
+    def on_survivor_selection(
+        self,
+        population: List[IndividualProtocol],
+        generation: int,
+        evaluations: int,
+    ) -> None:
+        """Called after the survivors of a generation have been selected.
+
+        Args:
+            population (List[IndividualProtocol]): The current population.
+            generation (int): The number of completed generations.
+            evaluations (int): The number of fitness evaluations used.
+        """
+
+    def on_termination(
+        self,
+        population: List[IndividualProtocol],
+        generation: int,
+        evaluations: int,
+    ) -> None:
+        """Called once the solver stops.
+
+        Args:
+            population (List[IndividualProtocol]): The final population.
+            generation (int): The number of completed generations.
+            evaluations (int): The number of fitness evaluations used.
+        """
+
+    def should_stop(self) -> bool:
+        """Requests the solver to stop after the current generation.
+
+        Returns:
+            bool: Whether the solver should stop.
+        """
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use pyo3::{FromPyObject, IntoPy, PyObject, Python};
 
-#[derive(FromPyObject)]
+#[derive(Clone, FromPyObject)]
 #[pyo3(transparent)]
 pub struct PyFitness {
     inner: PyObject,
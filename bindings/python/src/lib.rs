@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 mod fitness;
 mod individual;
 mod mutation;
+mod observer;
 mod rand;
 mod recombination;
 mod selection;
@@ -0,0 +1,180 @@
+use std::cell::RefCell;
+
+use metaheurustics::{observer::Observer, population::Population, solver::RunState};
+use pyo3::{PyErr, PyObject, PyResult, Python};
+
+use crate::{fitness::PyFitness, individual::PyIndividual};
+
+/// Forwards the hooks of a run to an optional Python observer.
+///
+/// The first exception raised by the observer is stored and stops the run, after which it is
+/// returned by [`PyObserver::finish`].
+pub struct PyObserver {
+    observer: Option<PyObject>,
+    error: RefCell<Option<PyErr>>,
+}
+
+impl PyObserver {
+    pub fn new(observer: Option<PyObject>) -> Self {
+        Self {
+            observer,
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Returns the first exception raised by the observer during the run.
+    pub fn finish(self) -> PyResult<()> {
+        match self.error.into_inner() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn record<T>(&self, result: PyResult<T>) -> Option<T> {
+        result
+            .map_err(|error| {
+                self.error.borrow_mut().get_or_insert(error);
+            })
+            .ok()
+    }
+
+    fn notify(
+        &self,
+        method: &str,
+        individuals: &[PyIndividual],
+        extra: Option<&[PyIndividual]>,
+        state: &RunState,
+    ) {
+        let result = self.try_notify(method, individuals, extra, state);
+        self.record(result);
+    }
+
+    fn try_notify(
+        &self,
+        method: &str,
+        individuals: &[PyIndividual],
+        extra: Option<&[PyIndividual]>,
+        state: &RunState,
+    ) -> PyResult<()> {
+        let observer = match &self.observer {
+            Some(observer) if self.error.borrow().is_none() => observer,
+            _ => return Ok(()),
+        };
+
+        Python::with_gil(|py| {
+            if !observer.as_ref(py).hasattr(method)? {
+                return Ok(());
+            }
+
+            let to_objects = |individuals: &[PyIndividual]| {
+                individuals
+                    .iter()
+                    .map(|individual| individual.individual().clone_ref(py))
+                    .collect::<Vec<_>>()
+            };
+
+            let population = to_objects(individuals);
+            let result = match extra {
+                Some(extra) => observer.call_method1(
+                    py,
+                    method,
+                    (
+                        population,
+                        to_objects(extra),
+                        state.generation,
+                        state.evaluations,
+                    ),
+                ),
+                None => observer.call_method1(
+                    py,
+                    method,
+                    (population, state.generation, state.evaluations),
+                ),
+            };
+            result.map(|_| ())
+        })
+    }
+
+    fn try_should_stop(&self) -> PyResult<bool> {
+        let observer = match &self.observer {
+            Some(observer) => observer,
+            None => return Ok(false),
+        };
+
+        Python::with_gil(|py| {
+            if !observer.as_ref(py).hasattr("should_stop")? {
+                return Ok(false);
+            }
+            observer.call_method0(py, "should_stop")?.extract(py)
+        })
+    }
+}
+
+impl Observer<PyIndividual, PyFitness> for PyObserver {
+    fn on_initialization(
+        &mut self,
+        population: &Population<PyIndividual, PyFitness>,
+        state: &RunState,
+    ) {
+        self.notify("on_initialization", population.individuals(), None, state);
+    }
+
+    fn on_parent_selection(
+        &mut self,
+        population: &Population<PyIndividual, PyFitness>,
+        mating_pool: &[&PyIndividual],
+        state: &RunState,
+    ) {
+        let mating_pool: Vec<_> = mating_pool.iter().map(|&x| x.clone()).collect();
+        self.notify(
+            "on_parent_selection",
+            population.individuals(),
+            Some(&mating_pool),
+            state,
+        );
+    }
+
+    fn on_offspring_evaluation(
+        &mut self,
+        population: &Population<PyIndividual, PyFitness>,
+        offspring: &[PyIndividual],
+        state: &RunState,
+    ) {
+        self.notify(
+            "on_offspring_evaluation",
+            population.individuals(),
+            Some(offspring),
+            state,
+        );
+    }
+
+    fn on_survivor_selection(
+        &mut self,
+        population: &Population<PyIndividual, PyFitness>,
+        state: &RunState,
+    ) {
+        self.notify(
+            "on_survivor_selection",
+            population.individuals(),
+            None,
+            state,
+        );
+    }
+
+    fn on_termination(
+        &mut self,
+        population: &Population<PyIndividual, PyFitness>,
+        state: &RunState,
+    ) {
+        self.notify("on_termination", population.individuals(), None, state);
+    }
+
+    fn should_stop(&self) -> bool {
+        if self.error.borrow().is_some() {
+            return true;
+        }
+
+        let result = self.try_should_stop();
+        self.record(result).unwrap_or(true)
+    }
+}
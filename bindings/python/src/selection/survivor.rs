@@ -10,12 +10,14 @@ pub enum PySurvivorSelector<'py> {
     ReplaceWorst(&'py PyCell<PyReplaceWorst>),
 }
 
-impl SurvivorSelector for PySurvivorSelector<'_> {
-    fn select<'a, R, I, F>(&self, rng: &mut R, individuals: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for PySurvivorSelector<'_>
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, rng: &mut R, individuals: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         match self {
             PySurvivorSelector::ReplaceWorst(selector) => {
@@ -40,12 +42,14 @@ impl PyReplaceWorst {
     }
 }
 
-impl SurvivorSelector for PyReplaceWorst {
-    fn select<'a, R, I, F>(&self, rng: &mut R, individuals: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for PyReplaceWorst
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, rng: &mut R, individuals: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         self.selector.select(rng, individuals, offspring);
     }
@@ -1,17 +1,18 @@
-use std::{
-    borrow::{Borrow, BorrowMut},
-    ops::DerefMut,
-};
+use std::{borrow::Borrow, ops::DerefMut};
 
 use metaheurustics::{
     individual::Individual,
+    observer::Observer,
+    population::Population,
     selection::{parent::ParentSelector, survivor::SurvivorSelector},
+    solver::RunState,
 };
 use pyo3::{pyclass, pymethods, types::PyFunction, IntoPy, Py, PyCell, PyObject, PyResult, Python};
 
 use crate::{
     individual::PyIndividual,
     mutation::PyIndividualMutator,
+    observer::PyObserver,
     rand::PySmallRng,
     recombination::PyIndividualRecombinator,
     selection::{parent::PyParentSelector, survivor::PySurvivorSelector},
@@ -51,37 +52,46 @@ impl PySolver {
         }
     }
 
+    #[pyo3(signature = (population_size, number_generations, observer=None))]
     fn solve<'py>(
         &'py self,
         py: Python<'py>,
         population_size: usize,
         number_generations: usize,
+        observer: Option<PyObject>,
     ) -> PyResult<Vec<PyObject>> {
         let parent_selector: PyParentSelector = self.parent_selector.extract(py)?;
         let survivor_selector: PySurvivorSelector = self.survivor_selector.extract(py)?;
         let rng = self.rng.extract::<&'py PyCell<PySmallRng>>(py)?;
-        let mut population = self
-            .initializer
-            .call1(py, (rng, population_size))?
-            .extract::<Vec<PyObject>>(py)?
-            .into_iter()
-            .map(PyIndividual::new)
-            .collect::<Vec<_>>();
+        let mut observer = PyObserver::new(observer);
+        let mut state = RunState::default();
+        let mut population = Population::new_from_individuals(
+            self.initializer
+                .call1(py, (rng, population_size))?
+                .extract::<Vec<PyObject>>(py)?
+                .into_iter()
+                .map(PyIndividual::new)
+                .collect::<Vec<_>>(),
+        );
 
-        for individual in population.iter_mut() {
+        for individual in population.individuals_mut().iter_mut() {
             let fitness = self
                 .evaluator
                 .call1(py, (individual.individual(),))?
                 .extract(py)?;
             individual.set_fitness(fitness);
         }
+        state.evaluations += population.len();
+        observer.on_initialization(&population, &state);
 
-        for _ in 0..number_generations {
+        while state.generation < number_generations && !observer.should_stop() {
             let mating_pool: Vec<_> = parent_selector.borrow().select(
                 rng.borrow_mut().deref_mut(),
-                &population,
+                population.individuals(),
                 population_size,
             );
+            observer.on_parent_selection(&population, &mating_pool, &state);
+
             let offspring: Vec<_> = mating_pool
                 .chunks(2)
                 .map(|x| {
@@ -102,14 +112,24 @@ impl PySolver {
                     .extract(py)?;
                 individual.borrow_mut().set_fitness(fitness);
             }
+            state.evaluations += offspring.len();
+            observer.on_offspring_evaluation(&population, &offspring, &state);
 
             survivor_selector.borrow().select(
                 rng.borrow_mut().deref_mut(),
-                population.borrow_mut(),
+                population.individuals_mut(),
                 offspring,
             );
+            state.generation += 1;
+            observer.on_survivor_selection(&population, &state);
         }
+        observer.on_termination(&population, &state);
+        observer.finish()?;
 
-        Ok(population.iter().map(|x| x.clone().into_py(py)).collect())
+        Ok(population
+            .individuals()
+            .iter()
+            .map(|x| x.clone().into_py(py))
+            .collect())
     }
 }
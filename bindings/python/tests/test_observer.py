@@ -0,0 +1,93 @@
+import random
+import unittest
+from typing import List
+
+import metaheurustics as mh
+
+
+class MyIndividual:
+    fitness: float
+    age: int
+
+    def __init__(self, value: int):
+        self.value = value
+
+    def get_fitness(self) -> float:
+        return self.fitness
+
+    def set_fitness(self, fitness: float) -> None:
+        self.fitness = fitness
+
+    def get_age(self) -> int:
+        return self.age
+
+    def set_age(self, age: int) -> None:
+        self.age = age
+
+
+class NoOpIndividualMutator:
+    def mutate(self, _rng: mh.SmallRng, individual: MyIndividual) -> MyIndividual:
+        return individual
+
+
+class NoOpIndividualRecombinator:
+    def recombine(
+        self,
+        _rng: mh.SmallRng,
+        parents: List[MyIndividual],
+    ) -> List[MyIndividual]:
+        return parents
+
+
+class FailingObserver:
+    def __init__(self, failing_generation: int):
+        self.failing_generation = failing_generation
+        self.generations: List[int] = []
+
+    def on_survivor_selection(
+        self, _population: List[MyIndividual], generation: int, _evaluations: int
+    ) -> None:
+        self.generations.append(generation)
+        if generation == self.failing_generation:
+            raise ValueError("observer failure")
+
+
+class FailingStopObserver:
+    def should_stop(self) -> bool:
+        raise RuntimeError("should_stop failure")
+
+
+def evaluate(individual: MyIndividual) -> float:
+    return -((individual.value - 50.0) ** 2)
+
+
+def initialize_population(_rng: mh.SmallRng, size: int) -> List[MyIndividual]:
+    return [MyIndividual(random.randint(0, 100)) for _ in range(size)]
+
+
+def solver() -> mh.Solver:
+    return mh.Solver(
+        mh.SmallRng(1234),
+        mh.LinearRanking(1.5),
+        NoOpIndividualRecombinator(),
+        NoOpIndividualMutator(),
+        mh.ReplaceWorst(0.1),
+        evaluate,
+        initialize_population,
+    )
+
+
+class ObserverErrorTest(unittest.TestCase):
+    def test_hook_error_stops_run(self):
+        observer = FailingObserver(failing_generation=3)
+        with self.assertRaisesRegex(ValueError, "observer failure"):
+            solver().solve(10, 100, observer)
+        self.assertEqual(observer.generations, [1, 2, 3])
+
+    def test_should_stop_error_is_raised(self):
+        with self.assertRaisesRegex(RuntimeError, "should_stop failure"):
+            solver().solve(10, 100, FailingStopObserver())
+
+
+if __name__ == "__main__":
+    unittest.main()
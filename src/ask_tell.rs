@@ -0,0 +1,162 @@
+use rand::Rng;
+
+use crate::{
+    individual::Individual,
+    mutation::Mutator,
+    population::Population,
+    recombination::Recombinator,
+    selection::{parent::ParentSelector, survivor::SurvivorSelector},
+    solver::{EvolutionaryAlgorithm, RunState},
+};
+
+/// Evolutionary algorithm driven by the caller, who evaluates the candidates handed out by
+/// `ask` and reports their fitnesses back through `tell`.
+///
+/// The first call to `ask` returns the initial population, subsequent calls return offspring.
+pub struct AskTellOptimizer<PS, C, M, SS, I, F, const N: usize, const K: usize = N>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    algorithm: EvolutionaryAlgorithm<PS, C, M, SS, (), N, K>,
+    population: Population<I, F>,
+    candidates: Option<Vec<I>>,
+    initialized: bool,
+    state: RunState,
+}
+
+impl<PS, C, M, SS, I, F, const N: usize, const K: usize> AskTellOptimizer<PS, C, M, SS, I, F, N, K>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    pub fn new(
+        population: Population<I, F>,
+        parent_selector: PS,
+        recombinator: C,
+        mutator: M,
+        survivor_selector: SS,
+        number_offspring: usize,
+    ) -> Self {
+        Self {
+            algorithm: EvolutionaryAlgorithm::new(
+                parent_selector,
+                recombinator,
+                mutator,
+                survivor_selector,
+                (),
+                number_offspring,
+            ),
+            population,
+            candidates: None,
+            initialized: false,
+            state: RunState::default(),
+        }
+    }
+
+    pub fn population(&self) -> &Population<I, F> {
+        &self.population
+    }
+
+    pub fn state(&self) -> &RunState {
+        &self.state
+    }
+
+    pub fn ask<R>(&mut self, rng: &mut R) -> &[I]
+    where
+        R: Rng + ?Sized,
+        PS: ParentSelector<F>,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+    {
+        if !self.initialized {
+            return self.population.individuals();
+        }
+
+        // Repeated calls hand out the same candidates until their fitnesses are told
+        if self.candidates.is_none() {
+            self.candidates = Some(self.algorithm.breed(rng, &self.population));
+        }
+
+        self.candidates.as_deref().unwrap()
+    }
+
+    pub fn tell<R>(&mut self, rng: &mut R, fitnesses: &[F])
+    where
+        R: Rng + ?Sized,
+        SS: SurvivorSelector<F>,
+    {
+        if !self.initialized {
+            self.population.set_fitnesses(fitnesses);
+            self.state.evaluations += fitnesses.len();
+            self.initialized = true;
+            return;
+        }
+
+        let mut candidates = self
+            .candidates
+            .take()
+            .expect("Candidates must be requested with `ask` before telling their fitnesses.");
+
+        if fitnesses.len() != candidates.len() {
+            panic!("Length of fitnesses must be equal to the amount of candidates.");
+        }
+
+        candidates
+            .iter_mut()
+            .zip(fitnesses)
+            .for_each(|(candidate, fitness)| {
+                candidate.set_fitness(fitness.clone());
+            });
+
+        self.state.evaluations += candidates.len();
+        self.algorithm
+            .select_survivors(rng, &mut self.population, candidates);
+        self.state.generation += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        benchmark::bent_cigar,
+        individual::{BoundedVectorIndividualMutator, BoundedVectorIndividualRecombinator},
+        mutation::UniformMutator,
+        recombination::SingleArithmetic,
+        selection::{parent::UniformSelector, survivor::ReplaceWorstSelector},
+    };
+
+    #[test]
+    fn test_ask_tell_loop() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut optimizer = AskTellOptimizer::new(
+            Population::new(&mut rng, -100.0, 100.0, 10, 50),
+            UniformSelector::new(),
+            BoundedVectorIndividualRecombinator::new(SingleArithmetic::new(0.5)),
+            BoundedVectorIndividualMutator::new(UniformMutator::new(0.01)),
+            ReplaceWorstSelector::new(0.5),
+            25,
+        );
+
+        let mut best_fitness = f64::NEG_INFINITY;
+        for generation in 0..20 {
+            let fitnesses: Vec<_> = optimizer
+                .ask(&mut rng)
+                .iter()
+                .map(|candidate| -bent_cigar(&candidate.vector().value))
+                .collect();
+            assert_eq!(fitnesses.len(), if generation == 0 { 50 } else { 25 });
+            optimizer.tell(&mut rng, &fitnesses);
+
+            let fitness = optimizer.population().best().unwrap().fitness();
+            assert!(fitness >= best_fitness);
+            best_fitness = fitness;
+        }
+
+        assert_eq!(optimizer.state().generation, 19);
+        assert_eq!(optimizer.state().evaluations, 50 + 19 * 25);
+    }
+}
@@ -0,0 +1,246 @@
+use rand::Rng;
+use rand_distr::Uniform;
+
+use crate::parameter::Bounds;
+
+/// Strategy repairing a real-valued gene that was moved outside of its bounds by an operator.
+pub trait BoundaryHandler {
+    /// Returns a feasible replacement for `value`.
+    ///
+    /// `parent` is the feasible value the gene was derived from and `sample` draws a new value
+    /// from the distribution of the operator that produced `value`.
+    fn repair<R, S>(
+        &self,
+        rng: &mut R,
+        value: f64,
+        parent: f64,
+        bounds: &Bounds<f64>,
+        sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64;
+}
+
+/// Moves violating values onto the violated bound.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clamp;
+
+/// Mirrors violating values back into the bounds at the violated bound.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reflect;
+
+/// Treats the bounds as toroidal, re-entering violating values from the opposite bound.
+///
+/// Genes unbounded on either side are clamped instead.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wrap;
+
+/// Replaces violating values by a uniformly sampled value within the bounds.
+///
+/// Genes unbounded on either side are clamped instead.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomReinitialization;
+
+/// Replaces violating values by the midpoint between the violated bound and the parent value.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MidpointToParent;
+
+/// Samples the operator again until the value is feasible, clamping after `max_attempts` tries.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resample {
+    max_attempts: usize,
+}
+
+impl Resample {
+    pub fn new(max_attempts: usize) -> Self {
+        Self { max_attempts }
+    }
+}
+
+impl Default for Resample {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl BoundaryHandler for Clamp {
+    fn repair<R, S>(
+        &self,
+        _rng: &mut R,
+        value: f64,
+        _parent: f64,
+        bounds: &Bounds<f64>,
+        _sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        bounds.clamp(value)
+    }
+}
+
+impl BoundaryHandler for Reflect {
+    fn repair<R, S>(
+        &self,
+        _rng: &mut R,
+        value: f64,
+        _parent: f64,
+        bounds: &Bounds<f64>,
+        _sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        match (bounds.min_value, bounds.max_value) {
+            (Some(min_value), Some(max_value)) => {
+                let width = max_value - min_value;
+                if width == 0.0 {
+                    return min_value;
+                }
+
+                // Reflecting repeatedly between both bounds is periodic in twice the width
+                let offset = (value - min_value).rem_euclid(2.0 * width);
+                match offset <= width {
+                    true => min_value + offset,
+                    false => max_value - (offset - width),
+                }
+            }
+            (Some(min_value), None) if value < min_value => 2.0 * min_value - value,
+            (None, Some(max_value)) if value > max_value => 2.0 * max_value - value,
+            _ => value,
+        }
+    }
+}
+
+impl BoundaryHandler for Wrap {
+    fn repair<R, S>(
+        &self,
+        _rng: &mut R,
+        value: f64,
+        _parent: f64,
+        bounds: &Bounds<f64>,
+        _sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        match bounds.range() {
+            Some((min_value, max_value)) if !bounds.contains(&value) => {
+                let width = max_value - min_value;
+                match width > 0.0 {
+                    true => min_value + (value - min_value).rem_euclid(width),
+                    false => min_value,
+                }
+            }
+            _ => bounds.clamp(value),
+        }
+    }
+}
+
+impl BoundaryHandler for RandomReinitialization {
+    fn repair<R, S>(
+        &self,
+        rng: &mut R,
+        value: f64,
+        _parent: f64,
+        bounds: &Bounds<f64>,
+        _sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        match bounds.range() {
+            Some((min_value, max_value)) if !bounds.contains(&value) => {
+                rng.sample(Uniform::new_inclusive(min_value, max_value))
+            }
+            _ => bounds.clamp(value),
+        }
+    }
+}
+
+impl BoundaryHandler for MidpointToParent {
+    fn repair<R, S>(
+        &self,
+        _rng: &mut R,
+        value: f64,
+        parent: f64,
+        bounds: &Bounds<f64>,
+        _sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        let bound = bounds.clamp(value);
+        match bound != value {
+            true => bounds.clamp((bound + parent) / 2.0),
+            false => value,
+        }
+    }
+}
+
+impl BoundaryHandler for Resample {
+    fn repair<R, S>(
+        &self,
+        rng: &mut R,
+        value: f64,
+        _parent: f64,
+        bounds: &Bounds<f64>,
+        mut sample: S,
+    ) -> f64
+    where
+        R: Rng + ?Sized,
+        S: FnMut(&mut R) -> f64,
+    {
+        if bounds.contains(&value) {
+            return value;
+        }
+
+        (0..self.max_attempts)
+            .map(|_| sample(rng))
+            .find(|value| bounds.contains(value))
+            .unwrap_or_else(|| bounds.clamp(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_repairs() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = Bounds::new(0.0, 10.0);
+        let no_sample = |_: &mut StdRng| -> f64 { unreachable!() };
+
+        assert_eq!(Clamp.repair(&mut rng, 12.0, 5.0, &bounds, no_sample), 10.0);
+        assert_eq!(Reflect.repair(&mut rng, 12.0, 5.0, &bounds, no_sample), 8.0);
+        assert_eq!(Reflect.repair(&mut rng, -3.0, 5.0, &bounds, no_sample), 3.0);
+        assert_eq!(Wrap.repair(&mut rng, 12.0, 5.0, &bounds, no_sample), 2.0);
+        assert_eq!(Wrap.repair(&mut rng, -3.0, 5.0, &bounds, no_sample), 7.0);
+        assert_eq!(
+            MidpointToParent.repair(&mut rng, 12.0, 6.0, &bounds, no_sample),
+            8.0
+        );
+        assert!(bounds
+            .contains(&RandomReinitialization.repair(&mut rng, 12.0, 5.0, &bounds, no_sample)));
+        assert_eq!(
+            Resample::default().repair(&mut rng, 12.0, 5.0, &bounds, |_| 4.0),
+            4.0
+        );
+        assert_eq!(Reflect.repair(&mut rng, 4.0, 5.0, &bounds, no_sample), 4.0);
+    }
+}
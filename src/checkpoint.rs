@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{individual::Individual, population::Population, solver::RunState};
+
+/// Snapshot of a run from which it can be resumed with identical continuation.
+///
+/// The random number generator is stored alongside the population, so it must be serializable
+/// itself, e.g. one of the `rand_chacha` generators with their `serde1` feature enabled.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "Population<I, F>: Serialize, R: Serialize",
+    deserialize = "Population<I, F>: Deserialize<'de>, R: Deserialize<'de>"
+))]
+pub struct Checkpoint<I, F, R>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    pub population: Population<I, F>,
+    pub rng: R,
+    pub state: RunState,
+}
+
+impl<I, F, R> Checkpoint<I, F, R>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    pub fn new(population: Population<I, F>, rng: R, state: RunState) -> Self {
+        Self {
+            population,
+            rng,
+            state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::{
+        benchmark::bent_cigar,
+        individual::{
+            BoundedVectorIndividual, BoundedVectorIndividualMutator,
+            BoundedVectorIndividualRecombinator, Individual,
+        },
+        mutation::SimpleGaussian,
+        recombination::SingleArithmetic,
+        selection::{
+            parent::Tournament, parent::TournamentSampleMethod, survivor::ReplaceWorstSelector,
+        },
+        solver::EvolutionaryAlgorithm,
+        termination::MaxGenerations,
+    };
+
+    #[test]
+    fn test_resume_from_checkpoint() {
+        let algorithm = EvolutionaryAlgorithm::new(
+            Tournament::new(2, TournamentSampleMethod::WithReplacement, 1.0),
+            BoundedVectorIndividualRecombinator::new(SingleArithmetic::new(0.5)),
+            BoundedVectorIndividualMutator::new(SimpleGaussian::new(1.0)),
+            ReplaceWorstSelector::new(0.5),
+            |individual: &BoundedVectorIndividual<f64, f64>| {
+                -bent_cigar(&individual.vector().value)
+            },
+            20,
+        );
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        let mut population = Population::new(&mut rng, -10.0, 10.0, 5, 20);
+        let state = algorithm.run(
+            &mut rng,
+            &mut population,
+            &mut MaxGenerations::new(5),
+            &mut (),
+        );
+
+        let checkpoint = Checkpoint::new(population, rng, state);
+        let serialized = serde_json::to_string(&checkpoint).unwrap();
+        let mut restored: Checkpoint<BoundedVectorIndividual<f64, f64>, f64, ChaCha8Rng> =
+            serde_json::from_str(&serialized).unwrap();
+
+        let Checkpoint {
+            mut population,
+            mut rng,
+            state,
+        } = checkpoint;
+        let state = algorithm.resume(
+            &mut rng,
+            &mut population,
+            state,
+            &mut MaxGenerations::new(10),
+            &mut (),
+        );
+        let restored_state = algorithm.resume(
+            &mut restored.rng,
+            &mut restored.population,
+            restored.state,
+            &mut MaxGenerations::new(10),
+            &mut (),
+        );
+
+        assert_eq!(state.generation, 10);
+        assert_eq!(restored_state.evaluations, state.evaluations);
+        population
+            .individuals()
+            .iter()
+            .zip(restored.population.individuals())
+            .for_each(|(a, b)| {
+                assert_eq!(a.vector().value, b.vector().value);
+                assert_eq!(a.fitness(), b.fitness());
+            });
+    }
+}
@@ -0,0 +1,315 @@
+use std::time::Instant;
+
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use rand::Rng;
+
+use crate::{
+    evaluation::Evaluator,
+    individual::{BoundedVectorIndividual, Individual},
+    observer::Observer,
+    parameter::{BoundedVector, Bounds},
+    population::Population,
+    samplers::sample_multivariate_gaussian,
+    solver::RunState,
+    termination::Termination,
+};
+
+/// Covariance Matrix Adaptation Evolution Strategy with cumulative step-size adaptation.
+///
+/// Candidates with a higher fitness are considered better, consistent with the selectors of this
+/// crate. Sampled candidates are clamped to the bounds of the initial vector for evaluation, while
+/// the distribution is updated from the unclamped samples.
+pub struct CmaEs {
+    mean: DVector<f64>,
+    sigma: f64,
+    covariance: DMatrix<f64>,
+    path_sigma: DVector<f64>,
+    path_c: DVector<f64>,
+    bounds: Vec<Bounds<f64>>,
+    lambda: usize,
+    weights: Vec<f64>,
+    mu_eff: f64,
+    c_sigma: f64,
+    d_sigma: f64,
+    c_c: f64,
+    c_1: f64,
+    c_mu: f64,
+    chi_n: f64,
+    generation: usize,
+    samples: Vec<DVector<f64>>,
+}
+
+impl CmaEs {
+    pub fn new(initial: BoundedVector<f64>, sigma: f64) -> Self {
+        let n = initial.value.len() as f64;
+        let lambda = 4 + (3.0 * n.ln()).floor() as usize;
+        Self::with_population_size(initial, sigma, lambda)
+    }
+
+    pub fn with_population_size(initial: BoundedVector<f64>, sigma: f64, lambda: usize) -> Self {
+        assert!(
+            lambda >= 2,
+            "CMA-ES requires a population size of at least 2"
+        );
+
+        let dimension = initial.value.len();
+        let n = dimension as f64;
+        let mu = lambda / 2;
+
+        let raw_weights: Vec<f64> = (1..=mu)
+            .map(|i| f64::ln(mu as f64 + 0.5) - f64::ln(i as f64))
+            .collect();
+        let sum_weights: f64 = raw_weights.iter().sum();
+        let weights: Vec<f64> = raw_weights.iter().map(|w| w / sum_weights).collect();
+        let mu_eff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let c_sigma = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let d_sigma =
+            1.0 + 2.0 * f64::max(0.0, f64::sqrt((mu_eff - 1.0) / (n + 1.0)) - 1.0) + c_sigma;
+        let c_c = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let c_1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+        let c_mu = f64::min(
+            1.0 - c_1,
+            2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff),
+        );
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        Self {
+            mean: DVector::from_vec(initial.value),
+            sigma,
+            covariance: DMatrix::identity(dimension, dimension),
+            path_sigma: DVector::zeros(dimension),
+            path_c: DVector::zeros(dimension),
+            bounds: initial.bounds,
+            lambda,
+            weights,
+            mu_eff,
+            c_sigma,
+            d_sigma,
+            c_c,
+            c_1,
+            c_mu,
+            chi_n,
+            generation: 0,
+            samples: Vec::new(),
+        }
+    }
+
+    pub fn mean(&self) -> BoundedVector<f64> {
+        BoundedVector::new(self.bounds.clone(), self.mean.iter().copied().collect())
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    pub fn covariance(&self) -> &DMatrix<f64> {
+        &self.covariance
+    }
+
+    pub fn population_size(&self) -> usize {
+        self.lambda
+    }
+
+    /// Samples a new population, which should be evaluated and passed to [`CmaEs::tell`] in the
+    /// same order.
+    pub fn ask<R, F>(&mut self, rng: &mut R) -> Population<BoundedVectorIndividual<f64, F>, F>
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy + Default,
+    {
+        let covariance = &self.covariance * self.sigma.powi(2);
+        let samples: Vec<Vec<f64>> = sample_multivariate_gaussian(
+            rng,
+            self.mean.as_slice(),
+            covariance.transpose().as_slice(),
+            self.lambda,
+        );
+        self.samples = samples
+            .iter()
+            .map(|x| DVector::from_column_slice(x))
+            .collect();
+
+        let individuals = samples
+            .into_iter()
+            .map(|sample| {
+                let mut vector = BoundedVector::new(self.bounds.clone(), sample);
+                vector.clamp();
+                BoundedVectorIndividual::new(vector)
+            })
+            .collect();
+
+        Population::new_from_individuals(individuals)
+    }
+
+    /// Updates the distribution from the evaluated population returned by the last call to
+    /// [`CmaEs::ask`].
+    pub fn tell<F>(&mut self, population: &Population<BoundedVectorIndividual<f64, F>, F>)
+    where
+        F: PartialOrd + Copy,
+    {
+        let n = self.mean.len() as f64;
+
+        let individuals = population.individuals();
+        assert_eq!(
+            individuals.len(),
+            self.samples.len(),
+            "The population should be the one returned by the last call to ask"
+        );
+        let mut ranking: Vec<usize> = (0..individuals.len()).collect();
+        ranking.sort_by(|&a, &b| individuals[b].compare_fitness(&individuals[a]));
+
+        let steps: Vec<DVector<f64>> = ranking
+            .iter()
+            .take(self.weights.len())
+            .map(|&i| (&self.samples[i] - &self.mean) / self.sigma)
+            .collect();
+        let weighted_step = steps
+            .iter()
+            .zip(&self.weights)
+            .fold(DVector::zeros(self.mean.len()), |acc, (y, w)| acc + y * *w);
+
+        self.mean += &weighted_step * self.sigma;
+
+        // C^(-1/2) from the eigendecomposition C = B D^2 B^T
+        let eigen = SymmetricEigen::new(self.covariance.clone());
+        let inverse_sqrt_eigenvalues =
+            DMatrix::from_diagonal(&eigen.eigenvalues.map(|x| 1.0 / x.max(f64::EPSILON).sqrt()));
+        let inverse_sqrt_covariance =
+            &eigen.eigenvectors * inverse_sqrt_eigenvalues * eigen.eigenvectors.transpose();
+
+        self.path_sigma = &self.path_sigma * (1.0 - self.c_sigma)
+            + &inverse_sqrt_covariance
+                * &weighted_step
+                * f64::sqrt(self.c_sigma * (2.0 - self.c_sigma) * self.mu_eff);
+
+        self.generation += 1;
+        let path_sigma_norm = self.path_sigma.norm();
+        let h_sigma = path_sigma_norm
+            / f64::sqrt(1.0 - (1.0 - self.c_sigma).powi(2 * self.generation as i32))
+            < (1.4 + 2.0 / (n + 1.0)) * self.chi_n;
+        let h_sigma = if h_sigma { 1.0 } else { 0.0 };
+
+        self.path_c = &self.path_c * (1.0 - self.c_c)
+            + &weighted_step * (h_sigma * f64::sqrt(self.c_c * (2.0 - self.c_c) * self.mu_eff));
+
+        let rank_one = &self.path_c * self.path_c.transpose();
+        let rank_mu = steps.iter().zip(&self.weights).fold(
+            DMatrix::zeros(self.mean.len(), self.mean.len()),
+            |acc, (y, w)| acc + (y * y.transpose()) * *w,
+        );
+        let correction = (1.0 - h_sigma) * self.c_c * (2.0 - self.c_c);
+
+        self.covariance = &self.covariance * (1.0 - self.c_1 - self.c_mu)
+            + (rank_one + &self.covariance * correction) * self.c_1
+            + rank_mu * self.c_mu;
+        // Guard against asymmetry caused by rounding errors
+        self.covariance = (&self.covariance + self.covariance.transpose()) * 0.5;
+
+        self.sigma *= f64::exp(self.c_sigma / self.d_sigma * (path_sigma_norm / self.chi_n - 1.0));
+    }
+
+    pub fn run<R, F, E, T, O>(
+        &mut self,
+        rng: &mut R,
+        evaluator: &E,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> (BoundedVectorIndividual<f64, F>, RunState)
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy + Default,
+        E: Evaluator<BoundedVectorIndividual<f64, F>, F>,
+        T: Termination<BoundedVectorIndividual<f64, F>, F>,
+        O: Observer<BoundedVectorIndividual<f64, F>, F>,
+    {
+        let mut lap = Instant::now();
+        let mut state = RunState::default();
+
+        let mut population = self.ask(rng);
+        population.evaluate(evaluator);
+        state.evaluations += population.len();
+        state.lap(&mut lap);
+        observer.on_initialization(&population, &state);
+        let mut best = population.best().unwrap().clone();
+
+        while !termination.is_terminated(&population, &state) && !observer.should_stop() {
+            self.tell(&population);
+            population = self.ask(rng);
+            population.evaluate(evaluator);
+            state.evaluations += population.len();
+            state.lap(&mut lap);
+            observer.on_offspring_evaluation(&population, population.individuals(), &state);
+
+            let generation_best = population.best().unwrap();
+            if generation_best.fitness() > best.fitness() {
+                best = generation_best.clone();
+            }
+            state.generation += 1;
+            state.lap(&mut lap);
+            observer.on_survivor_selection(&population, &state);
+        }
+
+        observer.on_termination(&population, &state);
+        (best, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{benchmark::bent_cigar, termination::MaxGenerations};
+
+    #[test]
+    fn test_bent_cigar_convergence() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut cma_es = CmaEs::new(
+            BoundedVector::with_shared_bounds(-100.0, 100.0, vec![10.0; 5]),
+            5.0,
+        );
+
+        let evaluation_func = |individual: &BoundedVectorIndividual<f64, f64>| {
+            -bent_cigar(&individual.vector().value)
+        };
+        let (best, state) = cma_es.run(
+            &mut rng,
+            &evaluation_func,
+            &mut MaxGenerations::new(500),
+            &mut (),
+        );
+
+        assert_eq!(state.evaluations, 501 * cma_es.population_size());
+        assert!(best.fitness() > -1e-8, "Best fitness: {}", best.fitness());
+    }
+
+    #[test]
+    fn test_update_from_unclamped_samples() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut cma_es = CmaEs::new(BoundedVector::with_shared_bounds(0.0, 1.0, vec![0.5]), 1.0);
+
+        let mut population = cma_es.ask(&mut rng);
+        assert!(population
+            .individuals()
+            .iter()
+            .all(|individual| (0.0..=1.0).contains(&individual.vector().value[0])));
+
+        // Ranks the samples by their unclamped value, so the expected mean is known
+        let samples: Vec<f64> = cma_es.samples.iter().map(|x| x[0]).collect();
+        population
+            .individuals_mut()
+            .iter_mut()
+            .zip(&samples)
+            .for_each(|(individual, &sample)| {
+                individual.set_fitness(-sample);
+            });
+        let mut ranked = samples.clone();
+        ranked.sort_by(f64::total_cmp);
+        let expected_mean: f64 = ranked.iter().zip(&cma_es.weights).map(|(x, w)| x * w).sum();
+
+        cma_es.tell(&population);
+        assert!((cma_es.mean().value[0] - expected_mean).abs() < 1e-12);
+        assert!(expected_mean < 0.0);
+    }
+}
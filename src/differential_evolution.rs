@@ -0,0 +1,501 @@
+use std::time::Instant;
+
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+use rand_distr::{Cauchy, Normal};
+
+pub use crate::recombination::differential::{DifferentialCrossover, DifferentialMutation};
+use crate::{
+    evaluation::Evaluator,
+    individual::{BoundedVectorIndividual, Individual},
+    observer::Observer,
+    parameter::BoundedVector,
+    population::Population,
+    recombination::differential::DifferentialRecombinator,
+    solver::RunState,
+    termination::Termination,
+};
+
+/// Generation of the scale factor and crossover rate of every trial vector.
+pub trait ParameterControl<F> {
+    /// Returns the scale factor and crossover rate of a single trial vector.
+    fn sample_parameters<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64);
+
+    /// Adapts to the scale factor, crossover rate, trial fitness and target fitness of every trial
+    /// vector improving on its target.
+    fn adapt_parameters(&mut self, successes: &[(f64, f64, F, F)]);
+}
+
+/// Constant scale factor and crossover rate, as in classic Differential Evolution.
+pub struct FixedControl {
+    scale_factor: f64,
+    crossover_rate: f64,
+}
+
+/// Scale factors and crossover rates sampled around means adapted to successful trial vectors.
+///
+/// SHADE weights the successes by their fitness improvement, which requires a fitness convertible
+/// into `f64`.
+pub struct AdaptiveControl {
+    memory: AdaptiveMemory,
+}
+
+enum AdaptiveMemory {
+    Jade {
+        learning_rate: f64,
+        mean_scale_factor: f64,
+        mean_crossover_rate: f64,
+    },
+    Shade {
+        memory_scale_factor: Vec<f64>,
+        memory_crossover_rate: Vec<f64>,
+        memory_index: usize,
+    },
+}
+
+impl<F> ParameterControl<F> for FixedControl {
+    fn sample_parameters<R: Rng + ?Sized>(&self, _rng: &mut R) -> (f64, f64) {
+        (self.scale_factor, self.crossover_rate)
+    }
+
+    fn adapt_parameters(&mut self, _successes: &[(f64, f64, F, F)]) {}
+}
+
+impl<F> ParameterControl<F> for AdaptiveControl
+where
+    F: Copy + Into<f64>,
+{
+    fn sample_parameters<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let (location_scale_factor, mean_crossover_rate) = match &self.memory {
+            AdaptiveMemory::Jade {
+                mean_scale_factor,
+                mean_crossover_rate,
+                ..
+            } => (*mean_scale_factor, *mean_crossover_rate),
+            AdaptiveMemory::Shade {
+                memory_scale_factor,
+                memory_crossover_rate,
+                ..
+            } => {
+                let r = rng.gen_range(0..memory_scale_factor.len());
+                (memory_scale_factor[r], memory_crossover_rate[r])
+            }
+        };
+
+        let crossover_rate = rng
+            .sample(Normal::new(mean_crossover_rate, 0.1).unwrap())
+            .clamp(0.0, 1.0);
+
+        // Scale factors are regenerated while not positive and truncated at 1
+        let distribution = Cauchy::new(location_scale_factor, 0.1).unwrap();
+        let scale_factor = loop {
+            let scale_factor = rng.sample(distribution);
+            if scale_factor > 0.0 {
+                break f64::min(scale_factor, 1.0);
+            }
+        };
+
+        (scale_factor, crossover_rate)
+    }
+
+    fn adapt_parameters(&mut self, successes: &[(f64, f64, F, F)]) {
+        if successes.is_empty() {
+            return;
+        }
+
+        let lehmer_mean = |weights: &[f64]| {
+            let numerator: f64 = successes
+                .iter()
+                .zip(weights)
+                .map(|((f, _, _, _), w)| w * f * f)
+                .sum();
+            let denominator: f64 = successes
+                .iter()
+                .zip(weights)
+                .map(|((f, _, _, _), w)| w * f)
+                .sum();
+            numerator / denominator
+        };
+        let arithmetic_mean = |weights: &[f64]| -> f64 {
+            successes
+                .iter()
+                .zip(weights)
+                .map(|((_, cr, _, _), w)| w * cr)
+                .sum()
+        };
+
+        match &mut self.memory {
+            AdaptiveMemory::Jade {
+                learning_rate,
+                mean_scale_factor,
+                mean_crossover_rate,
+            } => {
+                let weights = vec![1.0 / successes.len() as f64; successes.len()];
+                *mean_crossover_rate = (1.0 - *learning_rate) * *mean_crossover_rate
+                    + *learning_rate * arithmetic_mean(&weights);
+                *mean_scale_factor = (1.0 - *learning_rate) * *mean_scale_factor
+                    + *learning_rate * lehmer_mean(&weights);
+            }
+            AdaptiveMemory::Shade {
+                memory_scale_factor,
+                memory_crossover_rate,
+                memory_index,
+            } => {
+                let improvements: Vec<f64> = successes
+                    .iter()
+                    .map(|&(_, _, trial, target)| trial.into() - target.into())
+                    .collect();
+                let sum_improvements: f64 = improvements.iter().sum();
+                let weights: Vec<f64> = match sum_improvements > 0.0 {
+                    true => improvements
+                        .iter()
+                        .map(|df| df / sum_improvements)
+                        .collect(),
+                    false => vec![1.0 / successes.len() as f64; successes.len()],
+                };
+                memory_crossover_rate[*memory_index] = arithmetic_mean(&weights);
+                memory_scale_factor[*memory_index] = lehmer_mean(&weights);
+                *memory_index = (*memory_index + 1) % memory_scale_factor.len();
+            }
+        }
+    }
+}
+
+/// Differential Evolution on populations of real-valued bounded vectors.
+///
+/// Trial vectors are generated by [`DifferentialRecombinator`], placing components leaving the
+/// bounds halfway between the target value and the violated bound.
+pub struct DifferentialEvolution<C = FixedControl> {
+    mutation: DifferentialMutation,
+    crossover: DifferentialCrossover,
+    control: C,
+    archive: Vec<BoundedVector<f64>>,
+}
+
+impl DifferentialEvolution {
+    pub fn new(
+        mutation: DifferentialMutation,
+        crossover: DifferentialCrossover,
+        scale_factor: f64,
+        crossover_rate: f64,
+    ) -> Self {
+        Self {
+            mutation,
+            crossover,
+            control: FixedControl {
+                scale_factor,
+                crossover_rate: crossover_rate.clamp(0.0, 1.0),
+            },
+            archive: Vec::new(),
+        }
+    }
+}
+
+impl DifferentialEvolution<AdaptiveControl> {
+    /// JADE: current-to-pbest/1/bin with an archive and adaptive means of the control parameters.
+    pub fn jade(p: f64, learning_rate: f64) -> Self {
+        Self {
+            mutation: DifferentialMutation::CurrentToPBest1 { p },
+            crossover: DifferentialCrossover::Binomial,
+            control: AdaptiveControl {
+                memory: AdaptiveMemory::Jade {
+                    learning_rate,
+                    mean_scale_factor: 0.5,
+                    mean_crossover_rate: 0.5,
+                },
+            },
+            archive: Vec::new(),
+        }
+    }
+
+    /// SHADE: JADE with a success history memory of the control parameters.
+    pub fn shade(p: f64, memory_size: usize) -> Self {
+        assert!(memory_size > 0, "The memory size of SHADE must be positive");
+
+        Self {
+            mutation: DifferentialMutation::CurrentToPBest1 { p },
+            crossover: DifferentialCrossover::Binomial,
+            control: AdaptiveControl {
+                memory: AdaptiveMemory::Shade {
+                    memory_scale_factor: vec![0.5; memory_size],
+                    memory_crossover_rate: vec![0.5; memory_size],
+                    memory_index: 0,
+                },
+            },
+            archive: Vec::new(),
+        }
+    }
+}
+
+impl<C> DifferentialEvolution<C> {
+    fn trial<R, F>(
+        &self,
+        rng: &mut R,
+        individuals: &[BoundedVectorIndividual<f64, F>],
+        ranking: &[usize],
+        target: usize,
+        recombinator: &DifferentialRecombinator,
+    ) -> BoundedVector<f64>
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy,
+    {
+        let size = individuals.len();
+        let vector = |i: usize| individuals[i].vector();
+
+        // Donors are distinct from each other and from the target
+        let donors: Vec<usize> = index::sample(rng, size - 1, self.number_random_donors())
+            .into_iter()
+            .map(|i| if i >= target { i + 1 } else { i })
+            .collect();
+
+        let mut parents: Vec<&BoundedVector<f64>> =
+            Some(target).into_iter().chain(donors).map(vector).collect();
+
+        match self.mutation {
+            DifferentialMutation::Best1 | DifferentialMutation::CurrentToBest1 => {
+                parents.insert(1, vector(ranking[0]));
+            }
+            DifferentialMutation::CurrentToPBest1 { p } => {
+                let number_best = ((p * size as f64).round() as usize).clamp(1, size);
+                parents.insert(1, vector(ranking[rng.gen_range(0..number_best)]));
+
+                // Last donor is drawn from the union of the population and the archive
+                let union_index = rng.gen_range(0..size - 1 + self.archive.len());
+                if union_index >= size - 1 {
+                    parents[3] = &self.archive[union_index - (size - 1)];
+                }
+            }
+            DifferentialMutation::Rand1 | DifferentialMutation::Rand2 => {}
+        }
+
+        recombinator.trial(rng, &parents)
+    }
+
+    /// Number of donors chosen uniformly from the population, excluding best vectors.
+    fn number_random_donors(&self) -> usize {
+        match self.mutation {
+            DifferentialMutation::Rand1 | DifferentialMutation::Rand2 => {
+                self.mutation.number_parents() - 1
+            }
+            DifferentialMutation::Best1
+            | DifferentialMutation::CurrentToBest1
+            | DifferentialMutation::CurrentToPBest1 { .. } => self.mutation.number_parents() - 2,
+        }
+    }
+
+    /// Performs a single generation and returns the number of fitness evaluations used.
+    pub fn step<R, F, E>(
+        &mut self,
+        rng: &mut R,
+        population: &mut Population<BoundedVectorIndividual<f64, F>, F>,
+        evaluator: &E,
+    ) -> usize
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy + Default,
+        C: ParameterControl<F>,
+        E: Evaluator<BoundedVectorIndividual<f64, F>, F>,
+    {
+        let size = population.len();
+        assert!(
+            size > self.number_random_donors(),
+            "The population size ({}) is too small for the number of donors ({})",
+            size,
+            self.number_random_donors()
+        );
+
+        let individuals = population.individuals();
+        let mut ranking: Vec<usize> = (0..size).collect();
+        ranking.sort_by(|&a, &b| individuals[b].compare_fitness(&individuals[a]));
+
+        let mut parameters = Vec::with_capacity(size);
+        let mut trials: Vec<_> = (0..size)
+            .map(|i| {
+                let (scale_factor, crossover_rate) = self.control.sample_parameters(rng);
+                parameters.push((scale_factor, crossover_rate));
+                let recombinator = DifferentialRecombinator::new(
+                    self.mutation,
+                    self.crossover,
+                    scale_factor,
+                    crossover_rate,
+                );
+                BoundedVectorIndividual::new(self.trial(
+                    rng,
+                    individuals,
+                    &ranking,
+                    i,
+                    &recombinator,
+                ))
+            })
+            .collect();
+
+        evaluator.evaluate(&mut trials);
+
+        let uses_archive = matches!(self.mutation, DifferentialMutation::CurrentToPBest1 { .. });
+        let mut successes = Vec::new();
+        population.increment_ages();
+        for ((target, trial), (scale_factor, crossover_rate)) in population
+            .individuals_mut()
+            .iter_mut()
+            .zip(trials)
+            .zip(parameters)
+        {
+            if trial.fitness() >= target.fitness() {
+                if trial.fitness() > target.fitness() {
+                    successes.push((
+                        scale_factor,
+                        crossover_rate,
+                        trial.fitness(),
+                        target.fitness(),
+                    ));
+                    if uses_archive {
+                        self.archive.push(target.vector().clone());
+                    }
+                }
+                *target = trial;
+            }
+        }
+
+        // Archive is kept at the size of the population by random removal
+        if self.archive.len() > size {
+            self.archive.shuffle(rng);
+            self.archive.truncate(size);
+        }
+
+        self.control.adapt_parameters(&successes);
+
+        size
+    }
+
+    pub fn run<R, F, E, T, O>(
+        &mut self,
+        rng: &mut R,
+        population: &mut Population<BoundedVectorIndividual<f64, F>, F>,
+        evaluator: &E,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> RunState
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy + Default,
+        C: ParameterControl<F>,
+        E: Evaluator<BoundedVectorIndividual<f64, F>, F>,
+        T: Termination<BoundedVectorIndividual<f64, F>, F>,
+        O: Observer<BoundedVectorIndividual<f64, F>, F>,
+    {
+        let start = Instant::now();
+        let mut state = RunState::default();
+
+        population.evaluate(evaluator);
+        state.evaluations += population.len();
+        state.elapsed = start.elapsed();
+        observer.on_initialization(population, &state);
+
+        while !termination.is_terminated(population, &state) && !observer.should_stop() {
+            state.evaluations += self.step(rng, population, evaluator);
+            state.generation += 1;
+            state.elapsed = start.elapsed();
+            observer.on_survivor_selection(population, &state);
+        }
+
+        observer.on_termination(population, &state);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::termination::MaxGenerations;
+
+    fn sphere(individual: &BoundedVectorIndividual<f64, f64>) -> f64 {
+        -individual.vector().value.iter().map(|x| x * x).sum::<f64>()
+    }
+
+    fn optimize<C: ParameterControl<f64>>(
+        mut differential_evolution: DifferentialEvolution<C>,
+    ) -> f64 {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut population = Population::new(&mut rng, -5.0, 5.0, 5, 30);
+        differential_evolution.run(
+            &mut rng,
+            &mut population,
+            &sphere,
+            &mut MaxGenerations::new(200),
+            &mut (),
+        );
+
+        assert!(population
+            .individuals()
+            .iter()
+            .flat_map(|individual| &individual.vector().value)
+            .all(|x| (-5.0..=5.0).contains(x)));
+
+        population.best().unwrap().fitness()
+    }
+
+    #[test]
+    fn test_classic_strategies() {
+        let strategies = [
+            (DifferentialMutation::Rand1, DifferentialCrossover::Binomial),
+            (DifferentialMutation::Best1, DifferentialCrossover::Binomial),
+            (
+                DifferentialMutation::CurrentToBest1,
+                DifferentialCrossover::Binomial,
+            ),
+            (
+                DifferentialMutation::Rand2,
+                DifferentialCrossover::Exponential,
+            ),
+        ];
+
+        for (mutation, crossover) in strategies {
+            let fitness = optimize(DifferentialEvolution::new(mutation, crossover, 0.5, 0.9));
+            assert!(fitness > -1e-3, "Best fitness: {}", fitness);
+        }
+    }
+
+    #[test]
+    fn test_partially_ordered_fitness() {
+        // Fitness without a conversion into f64, which only the adaptive variants require
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        struct Score(f64);
+
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut population = Population::new(&mut rng, -5.0, 5.0, 5, 30);
+        DifferentialEvolution::new(
+            DifferentialMutation::Best1,
+            DifferentialCrossover::Binomial,
+            0.5,
+            0.9,
+        )
+        .run(
+            &mut rng,
+            &mut population,
+            &|individual: &BoundedVectorIndividual<f64, Score>| {
+                Score(-individual.vector().value.iter().map(|x| x * x).sum::<f64>())
+            },
+            &mut MaxGenerations::new(200),
+            &mut (),
+        );
+
+        let Score(fitness) = population.best().unwrap().fitness();
+        assert!(fitness > -1e-3, "Best fitness: {}", fitness);
+    }
+
+    #[test]
+    fn test_adaptive_variants() {
+        for differential_evolution in [
+            DifferentialEvolution::jade(0.1, 0.1),
+            DifferentialEvolution::shade(0.1, 10),
+        ] {
+            let fitness = optimize(differential_evolution);
+            assert!(fitness > -1e-3, "Best fitness: {}", fitness);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::individual::Individual;
+
+/// Assigns fitnesses to a batch of individuals.
+pub trait Evaluator<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd,
+{
+    fn evaluate(&self, individuals: &mut [I]);
+}
+
+impl<I, F, E> Evaluator<I, F> for E
+where
+    I: Individual<F>,
+    F: PartialOrd,
+    E: Fn(&I) -> F,
+{
+    fn evaluate(&self, individuals: &mut [I]) {
+        individuals.iter_mut().for_each(|individual| {
+            let fitness = self(individual);
+            individual.set_fitness(fitness);
+        });
+    }
+}
+
+/// Evaluates the individuals of a batch concurrently on the rayon thread pool.
+///
+/// Fitnesses are assigned by position, so results do not depend on the scheduling of the
+/// threads as long as the fitness function itself is deterministic.
+#[cfg(feature = "rayon")]
+pub struct ParallelEvaluator<E> {
+    evaluator: E,
+}
+
+#[cfg(feature = "rayon")]
+impl<E> ParallelEvaluator<E> {
+    pub fn new(evaluator: E) -> Self {
+        Self { evaluator }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<I, F, E> Evaluator<I, F> for ParallelEvaluator<E>
+where
+    I: Individual<F> + Send,
+    F: PartialOrd + Send,
+    E: Fn(&I) -> F + Sync,
+{
+    fn evaluate(&self, individuals: &mut [I]) {
+        individuals.par_iter_mut().for_each(|individual| {
+            let fitness = (self.evaluator)(individual);
+            individual.set_fitness(fitness);
+        });
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        benchmark::bent_cigar, individual::BoundedVectorIndividual, population::Population,
+    };
+
+    #[test]
+    fn test_parallel_evaluation_is_deterministic() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut population: Population<BoundedVectorIndividual<f64, f64>, f64> =
+            Population::new(&mut rng, -10.0, 10.0, 10, 100);
+        let evaluation_func =
+            |individual: &BoundedVectorIndividual<f64, f64>| bent_cigar(&individual.vector().value);
+
+        population.evaluate(&ParallelEvaluator::new(evaluation_func));
+        let parallel_fitnesses: Vec<_> = population
+            .individuals()
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
+
+        population.evaluate(&evaluation_func);
+        population
+            .individuals()
+            .iter()
+            .zip(parallel_fitnesses)
+            .for_each(|(individual, fitness)| assert_eq!(individual.fitness(), fitness));
+    }
+}
@@ -0,0 +1,370 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    boundary::{BoundaryHandler, Clamp},
+    evaluation::Evaluator,
+    individual::{BoundedVectorIndividual, BoundedVectorIndividualMutator, Individual},
+    mutation::{Mutator, SimpleGaussian},
+    observer::Observer,
+    population::Population,
+    selection::{
+        parent::UniformSelector,
+        survivor::{GenerationalRanked, MergeRanked},
+    },
+    solver::{EvolutionaryAlgorithm, RunState},
+    termination::Termination,
+};
+
+/// (1+1)-ES on a single real-valued bounded vector, adapting the step size by Rechenberg's 1/5th
+/// success rule.
+///
+/// Every `adaptation_period` generations the step size is divided by `adaptation_factor` when more
+/// than a fifth of the mutations improved the parent and multiplied by it when fewer did.
+pub struct OnePlusOneEs<B = Clamp> {
+    sigma: f64,
+    adaptation_factor: f64,
+    adaptation_period: usize,
+    generations: usize,
+    successes: usize,
+    boundary_handler: B,
+}
+
+impl OnePlusOneEs {
+    pub fn new(sigma: f64) -> Self {
+        Self::with_adaptation(sigma, 0.817, 10)
+    }
+
+    pub fn with_adaptation(sigma: f64, adaptation_factor: f64, adaptation_period: usize) -> Self {
+        assert!(
+            adaptation_factor > 0.0 && adaptation_factor < 1.0,
+            "The adaptation factor should be within (0, 1)"
+        );
+        assert!(
+            adaptation_period > 0,
+            "The adaptation period should be positive"
+        );
+
+        Self {
+            sigma,
+            adaptation_factor,
+            adaptation_period,
+            generations: 0,
+            successes: 0,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> OnePlusOneEs<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(self, boundary_handler: H) -> OnePlusOneEs<H> {
+        OnePlusOneEs {
+            sigma: self.sigma,
+            adaptation_factor: self.adaptation_factor,
+            adaptation_period: self.adaptation_period,
+            generations: self.generations,
+            successes: self.successes,
+            boundary_handler,
+        }
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    fn adapt_sigma(&mut self, success: bool) {
+        self.generations += 1;
+        self.successes += success as usize;
+        if self.generations < self.adaptation_period {
+            return;
+        }
+
+        let success_rate = self.successes as f64 / self.generations as f64;
+        if success_rate > 0.2 {
+            self.sigma /= self.adaptation_factor;
+        } else if success_rate < 0.2 {
+            self.sigma *= self.adaptation_factor;
+        }
+
+        self.generations = 0;
+        self.successes = 0;
+    }
+
+    pub fn step<R, F, E, O>(
+        &mut self,
+        rng: &mut R,
+        population: &mut Population<BoundedVectorIndividual<f64, F>, F>,
+        evaluator: &E,
+        state: &mut RunState,
+        observer: &mut O,
+    ) where
+        R: Rng + ?Sized,
+        F: PartialOrd + Clone + Default,
+        B: BoundaryHandler + Clone,
+        E: Evaluator<BoundedVectorIndividual<f64, F>, F>,
+        O: Observer<BoundedVectorIndividual<f64, F>, F>,
+    {
+        assert_eq!(
+            population.len(),
+            1,
+            "The population of a (1+1)-ES should contain a single individual"
+        );
+        let mut lap = Instant::now();
+
+        let parent = population.individuals()[0].clone();
+        state.lap(&mut lap);
+        observer.on_parent_selection(population, &[&parent], state);
+
+        let mutator = BoundedVectorIndividualMutator::new(
+            SimpleGaussian::new(self.sigma).with_boundary_handler(self.boundary_handler.clone()),
+        );
+        let mut child = parent;
+        mutator.mutate(rng, &mut child);
+        child.set_age(0);
+        let mut offspring = vec![child];
+        evaluator.evaluate(&mut offspring);
+        state.evaluations += offspring.len();
+        state.lap(&mut lap);
+        observer.on_offspring_evaluation(population, &offspring, state);
+
+        population.increment_ages();
+        let child = offspring.pop().unwrap();
+        let parent = &mut population.individuals_mut()[0];
+        let success = child.fitness() > parent.fitness();
+        if child.fitness() >= parent.fitness() {
+            *parent = child;
+        }
+        self.adapt_sigma(success);
+        state.generation += 1;
+        state.lap(&mut lap);
+        observer.on_survivor_selection(population, state);
+    }
+
+    pub fn run<R, F, E, T, O>(
+        &mut self,
+        rng: &mut R,
+        population: &mut Population<BoundedVectorIndividual<f64, F>, F>,
+        evaluator: &E,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> RunState
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Clone + Default,
+        B: BoundaryHandler + Clone,
+        E: Evaluator<BoundedVectorIndividual<f64, F>, F>,
+        T: Termination<BoundedVectorIndividual<f64, F>, F>,
+        O: Observer<BoundedVectorIndividual<f64, F>, F>,
+    {
+        let mut lap = Instant::now();
+        let mut state = RunState::default();
+
+        population.evaluate(evaluator);
+        state.evaluations += population.len();
+        state.lap(&mut lap);
+        observer.on_initialization(population, &state);
+
+        while !termination.is_terminated(population, &state) && !observer.should_stop() {
+            self.step(rng, population, evaluator, &mut state, observer);
+        }
+
+        observer.on_termination(population, &state);
+        state
+    }
+}
+
+/// (mu/rho + lambda)-ES, keeping the best `mu` of the parents and offspring.
+pub type PlusStrategy<C, M, E, const RHO: usize> =
+    EvolutionaryAlgorithm<UniformSelector, C, M, MergeRanked, E, RHO, 1>;
+
+/// (mu/rho, lambda)-ES, keeping the best `mu` of the offspring only.
+pub type CommaStrategy<C, M, E, const RHO: usize> =
+    EvolutionaryAlgorithm<UniformSelector, C, M, GenerationalRanked, E, RHO, 1>;
+
+/// Builds a (mu/rho + lambda)-ES, where `mu` is the size of the population it is run on.
+///
+/// Every offspring recombines `RHO` uniformly chosen parents into a single child before mutation.
+pub fn plus_strategy<C, M, E, const RHO: usize>(
+    recombinator: C,
+    mutator: M,
+    evaluator: E,
+    lambda: usize,
+) -> PlusStrategy<C, M, E, RHO> {
+    EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        recombinator,
+        mutator,
+        MergeRanked,
+        evaluator,
+        lambda,
+    )
+}
+
+/// Builds a (mu/rho, lambda)-ES, where `mu` is the size of the population it is run on and should
+/// not exceed `lambda`.
+///
+/// Every offspring recombines `RHO` uniformly chosen parents into a single child before mutation.
+pub fn comma_strategy<C, M, E, const RHO: usize>(
+    recombinator: C,
+    mutator: M,
+    evaluator: E,
+    lambda: usize,
+) -> CommaStrategy<C, M, E, RHO> {
+    EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        recombinator,
+        mutator,
+        GenerationalRanked,
+        evaluator,
+        lambda,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        boundary::Reflect,
+        individual::{GenomeIndividual, GenomeIndividualMutator, GenomeIndividualRecombinator},
+        mutation::{LogNormal, SelfAdaptiveGaussianVectorMutator},
+        parameter::{BoundedValue, BoundedVector, SelfAdaptiveGaussianVector},
+        recombination::{
+            multi_parent::{GlobalDiscrete, GlobalIntermediate},
+            SelfAdaptiveGaussianVectorRecombinator,
+        },
+        termination::MaxGenerations,
+    };
+
+    type Genome = SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>;
+
+    fn sphere(x: &[f64]) -> f64 {
+        -x.iter().map(|x| x * x).sum::<f64>()
+    }
+
+    fn initial_population(
+        rng: &mut StdRng,
+        mu: usize,
+    ) -> Population<GenomeIndividual<Genome, f64>, f64> {
+        let individuals = (0..mu)
+            .map(|_| {
+                GenomeIndividual::new(SelfAdaptiveGaussianVector {
+                    value: BoundedVector::with_shared_bounds(
+                        -5.0,
+                        5.0,
+                        (0..5).map(|_| rng.gen_range(-5.0..5.0)).collect(),
+                    ),
+                    strategy_parameter: BoundedValue {
+                        min_value: 0.0,
+                        max_value: 5.0,
+                        value: 1.0,
+                    },
+                })
+            })
+            .collect();
+        Population::new_from_individuals(individuals)
+    }
+
+    #[derive(Default)]
+    struct HookCounter {
+        parents: usize,
+        offspring: usize,
+        generations: usize,
+    }
+
+    impl Observer<BoundedVectorIndividual<f64, f64>, f64> for HookCounter {
+        fn on_parent_selection(
+            &mut self,
+            _population: &Population<BoundedVectorIndividual<f64, f64>, f64>,
+            mating_pool: &[&BoundedVectorIndividual<f64, f64>],
+            _state: &RunState,
+        ) {
+            self.parents += mating_pool.len();
+        }
+
+        fn on_offspring_evaluation(
+            &mut self,
+            _population: &Population<BoundedVectorIndividual<f64, f64>, f64>,
+            offspring: &[BoundedVectorIndividual<f64, f64>],
+            _state: &RunState,
+        ) {
+            self.offspring += offspring.len();
+        }
+
+        fn on_survivor_selection(
+            &mut self,
+            _population: &Population<BoundedVectorIndividual<f64, f64>, f64>,
+            _state: &RunState,
+        ) {
+            self.generations += 1;
+        }
+    }
+
+    #[test]
+    fn test_one_plus_one() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut population = Population::new(&mut rng, -5.0, 5.0, 5, 1);
+        let mut strategy = OnePlusOneEs::new(1.0).with_boundary_handler(Reflect);
+        let mut observer = HookCounter::default();
+
+        let state = strategy.run(
+            &mut rng,
+            &mut population,
+            &|individual: &BoundedVectorIndividual<f64, f64>| sphere(&individual.vector().value),
+            &mut MaxGenerations::new(1000),
+            &mut observer,
+        );
+
+        assert_eq!(state.evaluations, 1001);
+        assert_eq!(observer.parents, 1000);
+        assert_eq!(observer.offspring, 1000);
+        assert_eq!(observer.generations, 1000);
+        assert!(population.best().unwrap().fitness() > -1e-6);
+        assert!(strategy.sigma() < 1e-2);
+    }
+
+    #[test]
+    fn test_plus_and_comma_strategies() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let evaluator =
+            |individual: &GenomeIndividual<Genome, f64>| sphere(&individual.genome().value.value);
+        let recombinator = || {
+            GenomeIndividualRecombinator::new(SelfAdaptiveGaussianVectorRecombinator::new(
+                GlobalDiscrete,
+                GlobalIntermediate,
+            ))
+        };
+        let mutator = || {
+            GenomeIndividualMutator::new(SelfAdaptiveGaussianVectorMutator::new(LogNormal::new(
+                0.5, 1e-6,
+            )))
+        };
+
+        let plus = plus_strategy::<_, _, _, 2>(recombinator(), mutator(), evaluator, 20);
+        let mut population = initial_population(&mut rng, 5);
+        plus.initialize(&mut population);
+        let mut best = population.best().unwrap().fitness();
+        let mut state = RunState::default();
+        for _ in 0..100 {
+            plus.step(&mut rng, &mut population, &mut state, &mut ());
+            let fitness = population.best().unwrap().fitness();
+            assert!(fitness >= best);
+            best = fitness;
+        }
+        assert!(best > -1e-3, "Best fitness: {}", best);
+
+        let comma = comma_strategy::<_, _, _, 2>(recombinator(), mutator(), evaluator, 20);
+        let mut population = initial_population(&mut rng, 5);
+        comma.run(
+            &mut rng,
+            &mut population,
+            &mut MaxGenerations::new(100),
+            &mut (),
+        );
+        let best = population.best().unwrap().fitness();
+        assert!(best > -1e-3, "Best fitness: {}", best);
+        assert!(population.individuals().iter().all(|x| x.age() == 0));
+    }
+}
@@ -23,23 +23,25 @@ where
     }
 }
 
-pub struct BoundedVectorIndividual<T, F>
+/// Individual wrapping any genome, such as a
+/// [`SelfAdaptiveGaussianVector`](crate::parameter::SelfAdaptiveGaussianVector).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenomeIndividual<G, F>
 where
-    T: PartialOrd,
     F: PartialOrd,
 {
-    vector: BoundedVector<T>,
+    genome: G,
     fitness: F,
     age: u32,
 }
 
-impl<T, F> Individual<F> for BoundedVectorIndividual<T, F>
+impl<G, F> Individual<F> for GenomeIndividual<G, F>
 where
-    T: PartialOrd,
-    F: PartialOrd + Copy,
+    F: PartialOrd + Clone,
 {
     fn fitness(&self) -> F {
-        self.fitness
+        self.fitness.clone()
     }
 
     fn set_fitness(&mut self, fitness: F) -> &mut Self {
@@ -57,114 +59,116 @@ where
     }
 }
 
-impl<T, F> BoundedVectorIndividual<T, F>
+impl<G, F> GenomeIndividual<G, F>
+where
+    F: PartialOrd,
+{
+    pub fn genome(&self) -> &G {
+        &self.genome
+    }
+}
+
+impl<T, F> GenomeIndividual<BoundedVector<T>, F>
 where
     T: PartialOrd,
     F: PartialOrd,
 {
     pub fn vector(&self) -> &BoundedVector<T> {
-        &self.vector
+        &self.genome
     }
 }
 
-impl<T, F> BoundedVectorIndividual<T, F>
+impl<G, F> GenomeIndividual<G, F>
 where
-    T: PartialOrd,
     F: PartialOrd + Default,
 {
-    pub fn new(vector: BoundedVector<T>) -> Self {
+    pub fn new(genome: G) -> Self {
         Self {
-            vector,
+            genome,
             fitness: Default::default(),
             age: 0,
         }
     }
 }
 
-pub struct BoundedVectorIndividualMutator<T, M>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenomeIndividualMutator<G, M>
 where
-    T: PartialOrd,
-    M: Mutator<BoundedVector<T>>,
+    M: Mutator<G>,
 {
-    vector_mutator: M,
-    _phantom: PhantomData<T>,
+    genome_mutator: M,
+    _phantom: PhantomData<G>,
 }
 
-impl<T, M> BoundedVectorIndividualMutator<T, M>
+impl<G, M> GenomeIndividualMutator<G, M>
 where
-    T: PartialOrd,
-    M: Mutator<BoundedVector<T>>,
+    M: Mutator<G>,
 {
-    pub fn new(vector_mutator: M) -> Self {
+    pub fn new(genome_mutator: M) -> Self {
         Self {
-            vector_mutator,
+            genome_mutator,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<T, M, F> Mutator<BoundedVectorIndividual<T, F>> for BoundedVectorIndividualMutator<T, M>
+impl<G, M, F> Mutator<GenomeIndividual<G, F>> for GenomeIndividualMutator<G, M>
 where
-    T: PartialOrd,
-    M: Mutator<BoundedVector<T>>,
-    F: PartialOrd + Copy + Default,
+    M: Mutator<G>,
+    F: PartialOrd + Clone + Default,
 {
     fn mutate<'a, R: rand::Rng + ?Sized>(
         &self,
         rng: &mut R,
-        parameter: &'a mut BoundedVectorIndividual<T, F>,
-    ) -> &'a mut BoundedVectorIndividual<T, F> {
-        self.vector_mutator.mutate(rng, &mut parameter.vector);
+        parameter: &'a mut GenomeIndividual<G, F>,
+    ) -> &'a mut GenomeIndividual<G, F> {
+        self.genome_mutator.mutate(rng, &mut parameter.genome);
         parameter
     }
 }
 
-pub struct BoundedVectorIndividualRecombinator<T, R, const N: usize>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenomeIndividualRecombinator<G, R, const N: usize, const K: usize = N>
 where
-    T: PartialOrd,
-    R: Recombinator<BoundedVector<T>, N>,
+    R: Recombinator<G, N, K>,
 {
-    vector_recombinator: R,
-    _phantom: PhantomData<T>,
+    genome_recombinator: R,
+    _phantom: PhantomData<G>,
 }
 
-impl<T, R, const N: usize> BoundedVectorIndividualRecombinator<T, R, N>
+impl<G, R, const N: usize, const K: usize> GenomeIndividualRecombinator<G, R, N, K>
 where
-    T: PartialOrd,
-    R: Recombinator<BoundedVector<T>, N>,
+    R: Recombinator<G, N, K>,
 {
-    pub fn new(vector_recombinator: R) -> Self {
+    pub fn new(genome_recombinator: R) -> Self {
         Self {
-            vector_recombinator,
+            genome_recombinator,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<C, T, F, const N: usize> Recombinator<BoundedVectorIndividual<T, F>, N>
-    for BoundedVectorIndividualRecombinator<T, C, N>
+impl<C, G, F, const N: usize, const K: usize> Recombinator<GenomeIndividual<G, F>, N, K>
+    for GenomeIndividualRecombinator<G, C, N, K>
 where
-    T: PartialOrd,
-    C: Recombinator<BoundedVector<T>, N>,
-    F: PartialOrd + Copy + Default,
+    C: Recombinator<G, N, K>,
+    F: PartialOrd + Clone + Default,
 {
     fn recombine<R: rand::Rng + ?Sized>(
         &self,
         rng: &mut R,
-        parents: &[&BoundedVectorIndividual<T, F>; N],
-    ) -> [BoundedVectorIndividual<T, F>; N] {
-        let values: Vec<_> = parents.iter().map(|parent| parent.vector()).collect();
-        let values_array = values[..].try_into().unwrap();
-        let recombined_individuals: Vec<_> = self
-            .vector_recombinator
-            .recombine(rng, values_array)
-            .into_iter()
-            .map(|value| BoundedVectorIndividual::new(value))
-            .collect();
-
-        match recombined_individuals.try_into() {
-            Ok(x) => x,
-            Err(_) => panic!("Recombination resulted in an unexpected amount of offspring."),
-        }
+        parents: &[&GenomeIndividual<G, F>; N],
+    ) -> [GenomeIndividual<G, F>; K] {
+        let genomes = parents.map(|parent| parent.genome());
+        self.genome_recombinator
+            .recombine(rng, &genomes)
+            .map(GenomeIndividual::new)
     }
 }
+
+pub type BoundedVectorIndividual<T, F> = GenomeIndividual<BoundedVector<T>, F>;
+
+pub type BoundedVectorIndividualMutator<T, M> = GenomeIndividualMutator<BoundedVector<T>, M>;
+
+pub type BoundedVectorIndividualRecombinator<T, R, const N: usize, const K: usize = N> =
+    GenomeIndividualRecombinator<BoundedVector<T>, R, N, K>;
@@ -1,8 +1,22 @@
+pub mod ask_tell;
 pub mod benchmark;
+pub mod boundary;
+#[cfg(feature = "serde")]
+pub mod checkpoint;
+pub mod cma_es;
+pub mod differential_evolution;
+pub mod evaluation;
+pub mod evolution_strategy;
 pub mod individual;
+pub mod multi_objective;
 pub mod mutation;
+pub mod observer;
 pub mod parameter;
 pub mod population;
+pub mod pso;
 pub mod recombination;
+pub mod recorder;
 pub mod samplers;
 pub mod selection;
+pub mod solver;
+pub mod termination;
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+
+/// Vector-valued fitness ordered by Pareto dominance, where every objective is maximized.
+///
+/// The ordering is partial: two fitnesses of which neither dominates the other are incomparable.
+/// Operators relying on a total order through `Individual::compare_fitness` will panic on such
+/// pairs, use the dominance-aware selectors instead.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiObjectiveFitness {
+    pub objectives: Vec<f64>,
+}
+
+impl MultiObjectiveFitness {
+    pub fn new(objectives: Vec<f64>) -> Self {
+        Self { objectives }
+    }
+
+    pub fn dominates(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Greater))
+    }
+}
+
+impl From<Vec<f64>> for MultiObjectiveFitness {
+    fn from(objectives: Vec<f64>) -> Self {
+        Self { objectives }
+    }
+}
+
+impl PartialOrd for MultiObjectiveFitness {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.objectives.len() != other.objectives.len() {
+            return None;
+        }
+
+        let mut better = false;
+        let mut worse = false;
+        for (a, b) in self.objectives.iter().zip(&other.objectives) {
+            match a.partial_cmp(b)? {
+                Ordering::Greater => better = true,
+                Ordering::Less => worse = true,
+                Ordering::Equal => {}
+            }
+        }
+
+        match (better, worse) {
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+            (false, false) => Some(Ordering::Equal),
+            (true, true) => None,
+        }
+    }
+}
+
+/// Partitions the fitnesses into fronts of mutually non-dominated solutions, best front first.
+pub fn fast_non_dominated_sort<F>(fitnesses: &[F]) -> Vec<Vec<usize>>
+where
+    F: PartialOrd,
+{
+    let size = fitnesses.len();
+    let mut dominated_sets = vec![Vec::new(); size];
+    let mut domination_counts = vec![0; size];
+    let mut fronts = vec![Vec::new()];
+
+    for p in 0..size {
+        for q in (p + 1)..size {
+            match fitnesses[p].partial_cmp(&fitnesses[q]) {
+                Some(Ordering::Greater) => {
+                    dominated_sets[p].push(q);
+                    domination_counts[q] += 1;
+                }
+                Some(Ordering::Less) => {
+                    dominated_sets[q].push(p);
+                    domination_counts[p] += 1;
+                }
+                _ => {}
+            }
+        }
+
+        if domination_counts[p] == 0 {
+            fronts[0].push(p);
+        }
+    }
+    // Later indices may have been dominated after being assigned to the first front
+    fronts[0].retain(|&p| domination_counts[p] == 0);
+
+    let mut current = 0;
+    while !fronts[current].is_empty() {
+        let mut next_front = Vec::new();
+        for &p in fronts[current].iter() {
+            for &q in dominated_sets[p].iter() {
+                domination_counts[q] -= 1;
+                if domination_counts[q] == 0 {
+                    next_front.push(q);
+                }
+            }
+        }
+        fronts.push(next_front);
+        current += 1;
+    }
+    fronts.pop();
+
+    fronts
+}
+
+/// Crowding distance of every member of a front, boundary solutions get an infinite distance.
+pub fn crowding_distance(fitnesses: &[MultiObjectiveFitness], front: &[usize]) -> Vec<f64> {
+    let mut distances = vec![0.0; front.len()];
+    let number_objectives = front.first().map_or(0, |&i| fitnesses[i].objectives.len());
+
+    for m in 0..number_objectives {
+        let objective = |k: usize| fitnesses[front[k]].objectives[m];
+
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| objective(a).total_cmp(&objective(b)));
+
+        let (first, last) = (order[0], order[order.len() - 1]);
+        distances[first] = f64::INFINITY;
+        distances[last] = f64::INFINITY;
+
+        let range = objective(last) - objective(first);
+        if range <= 0.0 {
+            continue;
+        }
+
+        for window in order.windows(3) {
+            distances[window[1]] += (objective(window[2]) - objective(window[0])) / range;
+        }
+    }
+
+    distances
+}
+
+/// Non-domination rank and crowding distance of every fitness, in the order of the given slice.
+pub fn crowded_ranking(fitnesses: &[MultiObjectiveFitness]) -> Vec<(usize, f64)> {
+    let mut ranking = vec![(0, 0.0); fitnesses.len()];
+    for (rank, front) in fast_non_dominated_sort(fitnesses).into_iter().enumerate() {
+        for (&i, distance) in front.iter().zip(crowding_distance(fitnesses, &front)) {
+            ranking[i] = (rank, distance);
+        }
+    }
+
+    ranking
+}
+
+/// Crowded-comparison operator, preferring a lower rank and then a larger crowding distance.
+pub fn crowded_compare(a: &(usize, f64), b: &(usize, f64)) -> Ordering {
+    b.0.cmp(&a.0).then(a.1.total_cmp(&b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominance() {
+        let a = MultiObjectiveFitness::new(vec![1.0, 2.0]);
+        let b = MultiObjectiveFitness::new(vec![0.0, 1.0]);
+        let c = MultiObjectiveFitness::new(vec![2.0, 0.0]);
+
+        assert!(a.dominates(&b));
+        assert!(!b.dominates(&a));
+        assert_eq!(a.partial_cmp(&c), None);
+    }
+
+    #[test]
+    fn test_fast_non_dominated_sort() {
+        let fitnesses: Vec<MultiObjectiveFitness> = [
+            vec![0.0, 0.0],
+            vec![1.0, 3.0],
+            vec![3.0, 1.0],
+            vec![2.0, 2.0],
+            vec![1.0, 1.0],
+        ]
+        .into_iter()
+        .map(MultiObjectiveFitness::from)
+        .collect();
+
+        let fronts = fast_non_dominated_sort(&fitnesses);
+        assert_eq!(fronts, vec![vec![1, 2, 3], vec![4], vec![0]]);
+
+        let distances = crowding_distance(&fitnesses, &fronts[0]);
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[1], f64::INFINITY);
+        assert_eq!(distances[2], 2.0);
+    }
+}
@@ -1,18 +1,30 @@
-use std::marker::PhantomData;
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    marker::PhantomData,
+};
 
 use rand::Rng;
-use rand_distr::{uniform::SampleUniform, Normal, Uniform};
+use rand_distr::{uniform::SampleUniform, Cauchy, Exp1, Normal, Uniform};
 
-use crate::parameter::{
-    BoundedValue, BoundedVector, GaussianStrategyParameter, SelfAdaptiveGaussianVector,
+use crate::{
+    boundary::{BoundaryHandler, Clamp},
+    parameter::{
+        BoundedValue, BoundedVector, Bounds, CorrelatedStrategyParameter,
+        GaussianStrategyParameter, SelfAdaptiveGaussianVector,
+    },
+    samplers::sample_rotated_gaussian,
 };
 
+pub mod bit_string;
 pub mod integer;
+pub mod mixed;
+pub mod permutation;
 
 pub trait Mutator<T> {
     fn mutate<'a, R: Rng + ?Sized>(&self, rng: &mut R, parameter: &'a mut T) -> &'a mut T;
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformMutator {
     probability: f64,
 }
@@ -34,28 +46,73 @@ where
         rng: &mut R,
         parameter: &'a mut BoundedVector<T>,
     ) -> &'a mut BoundedVector<T> {
-        let distribution = Uniform::new_inclusive(parameter.min_value, parameter.max_value);
-        parameter.value.iter_mut().for_each(|value| {
-            let random_value = rng.sample(Uniform::new(0.0, 1.0));
-            if random_value <= self.probability {
-                *value = rng.sample(&distribution);
-            }
-        });
+        parameter
+            .value
+            .iter_mut()
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                let random_value = rng.sample(Uniform::new(0.0, 1.0));
+                if random_value <= self.probability {
+                    let (min_value, max_value) = bounds
+                        .range()
+                        .expect("Uniform mutation requires finite bounds for every gene");
+                    *value = rng.sample(Uniform::new_inclusive(min_value, max_value));
+                }
+            });
+        parameter
+    }
+}
+
+impl<T> Mutator<BoundedValue<T>> for UniformMutator
+where
+    T: PartialOrd + SampleUniform + Copy,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedValue<T>,
+    ) -> &'a mut BoundedValue<T> {
+        if rng.sample(Uniform::new(0.0, 1.0)) <= self.probability {
+            parameter.value = rng.sample(Uniform::new_inclusive(
+                parameter.min_value,
+                parameter.max_value,
+            ));
+        }
         parameter
     }
 }
 
-pub struct SimpleGaussian<T> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimpleGaussian<T, B = Clamp> {
     std: T,
+    boundary_handler: B,
 }
 
 impl<T> SimpleGaussian<T> {
     pub fn new(std: T) -> Self {
-        Self { std }
+        Self {
+            std,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<T, B> SimpleGaussian<T, B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> SimpleGaussian<T, H> {
+        SimpleGaussian {
+            std: self.std,
+            boundary_handler,
+        }
     }
 }
 
-impl Mutator<BoundedVector<f64>> for SimpleGaussian<f64> {
+impl<B> Mutator<BoundedVector<f64>> for SimpleGaussian<f64, B>
+where
+    B: BoundaryHandler,
+{
     fn mutate<'a, R: Rng + ?Sized>(
         &self,
         rng: &mut R,
@@ -65,56 +122,516 @@ impl Mutator<BoundedVector<f64>> for SimpleGaussian<f64> {
         parameter
             .value
             .iter_mut()
-            .zip(rng.sample_iter(distribution))
-            .for_each(|(value, mutation)| {
-                *value = (*value + mutation).clamp(parameter.min_value, parameter.max_value);
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                let parent = *value;
+                let sample = |rng: &mut R| parent + rng.sample(distribution);
+                let mutated = sample(rng);
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutated, parent, bounds, sample);
             });
 
         parameter
     }
 }
 
-pub struct LogNormal<T> {
+impl<B> Mutator<BoundedValue<f64>> for SimpleGaussian<f64, B>
+where
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedValue<f64>,
+    ) -> &'a mut BoundedValue<f64> {
+        let distribution = Normal::new(0.0, self.std).unwrap();
+        let bounds = Bounds::new(parameter.min_value, parameter.max_value);
+
+        let parent = parameter.value;
+        let sample = |rng: &mut R| parent + rng.sample(distribution);
+        let mutated = sample(rng);
+        parameter.value = self
+            .boundary_handler
+            .repair(rng, mutated, parent, &bounds, sample);
+
+        parameter
+    }
+}
+
+/// Adds Cauchy distributed perturbations with the given scale to every gene, as in Fast
+/// Evolutionary Programming.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CauchyMutation<B = Clamp> {
+    scale: f64,
+    boundary_handler: B,
+}
+
+impl CauchyMutation {
+    pub fn new(scale: f64) -> Self {
+        Self {
+            scale,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> CauchyMutation<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> CauchyMutation<H> {
+        CauchyMutation {
+            scale: self.scale,
+            boundary_handler,
+        }
+    }
+}
+
+impl<B> Mutator<BoundedVector<f64>> for CauchyMutation<B>
+where
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedVector<f64>,
+    ) -> &'a mut BoundedVector<f64> {
+        let distribution = Cauchy::new(0.0, self.scale).unwrap();
+        parameter
+            .value
+            .iter_mut()
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                let parent = *value;
+                let sample = |rng: &mut R| parent + rng.sample(distribution);
+                let mutated = sample(rng);
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutated, parent, bounds, sample);
+            });
+
+        parameter
+    }
+}
+
+/// Lévy flight mutation, adding symmetric alpha-stable perturbations with stability `alpha` in
+/// `(0, 2]` and the given scale to every gene.
+///
+/// Smaller values of `alpha` produce heavier tails, `alpha = 1` is the Cauchy and `alpha = 2` the
+/// Gaussian distribution.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevyMutation<B = Clamp> {
+    alpha: f64,
+    scale: f64,
+    boundary_handler: B,
+}
+
+impl LevyMutation {
+    pub fn new(alpha: f64, scale: f64) -> Self {
+        assert!(
+            alpha > 0.0 && alpha <= 2.0,
+            "The stability parameter should be within (0, 2]"
+        );
+
+        Self {
+            alpha,
+            scale,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> LevyMutation<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(self, boundary_handler: H) -> LevyMutation<H> {
+        LevyMutation {
+            alpha: self.alpha,
+            scale: self.scale,
+            boundary_handler,
+        }
+    }
+
+    /// Symmetric alpha-stable sample by the method of Chambers, Mallows and Stuck.
+    fn sample_step<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let v = rng.gen_range(-FRAC_PI_2..FRAC_PI_2);
+        let w: f64 = rng.sample(Exp1);
+
+        let step = match self.alpha == 1.0 {
+            true => v.tan(),
+            false => {
+                (self.alpha * v).sin() / v.cos().powf(1.0 / self.alpha)
+                    * (((1.0 - self.alpha) * v).cos() / w).powf((1.0 - self.alpha) / self.alpha)
+            }
+        };
+        self.scale * step
+    }
+}
+
+impl<B> Mutator<BoundedVector<f64>> for LevyMutation<B>
+where
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedVector<f64>,
+    ) -> &'a mut BoundedVector<f64> {
+        parameter
+            .value
+            .iter_mut()
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                let parent = *value;
+                let sample = |rng: &mut R| parent + self.sample_step(rng);
+                let mutated = sample(rng);
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutated, parent, bounds, sample);
+            });
+
+        parameter
+    }
+}
+
+/// Bounded polynomial mutation with distribution index `eta`, mutating every gene with the given
+/// probability.
+///
+/// The perturbation is scaled to the width of the bounds of each gene. Genes without finite bounds
+/// use the unbounded perturbation scaled by the step width instead, 1 by default, and are clamped
+/// to a remaining bound. A larger `eta` results in smaller perturbations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolynomialMutation {
+    eta: f64,
+    probability: f64,
+    step_width: f64,
+}
+
+impl PolynomialMutation {
+    pub fn new(eta: f64, probability: f64) -> Self {
+        assert!(eta >= 0.0, "The distribution index should be non-negative");
+        Self {
+            eta,
+            probability: probability.clamp(0.0, 1.0),
+            step_width: 1.0,
+        }
+    }
+
+    /// Sets the scale of the perturbation of genes without finite bounds.
+    pub fn with_step_width(self, step_width: f64) -> Self {
+        assert!(step_width > 0.0, "The step width should be positive");
+        Self { step_width, ..self }
+    }
+}
+
+impl Mutator<BoundedVector<f64>> for PolynomialMutation {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedVector<f64>,
+    ) -> &'a mut BoundedVector<f64> {
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        parameter
+            .value
+            .iter_mut()
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                if rng.sample(Uniform::new(0.0, 1.0)) > self.probability {
+                    return;
+                }
+
+                // Without finite bounds the bound terms vanish, leaving Deb's unbounded perturbation
+                let (width, delta_1, delta_2) = match bounds.range() {
+                    Some((min_value, max_value)) => {
+                        let width = max_value - min_value;
+                        if width <= 0.0 {
+                            return;
+                        }
+                        (
+                            width,
+                            (*value - min_value) / width,
+                            (max_value - *value) / width,
+                        )
+                    }
+                    None => (self.step_width, 1.0, 1.0),
+                };
+                let u = rng.gen_range(0.0..1.0);
+
+                let delta = match u < 0.5 {
+                    true => {
+                        let v = 2.0 * u + (1.0 - 2.0 * u) * (1.0 - delta_1).powf(self.eta + 1.0);
+                        v.powf(exponent) - 1.0
+                    }
+                    false => {
+                        let v = 2.0 * (1.0 - u)
+                            + 2.0 * (u - 0.5) * (1.0 - delta_2).powf(self.eta + 1.0);
+                        1.0 - v.powf(exponent)
+                    }
+                };
+
+                *value = bounds.clamp(*value + delta * width);
+            });
+
+        parameter
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogNormal<T, B = Clamp> {
     std: T,
     min_value: T,
+    boundary_handler: B,
 }
 
 impl<T> LogNormal<T> {
     pub fn new(std: T, min_value: T) -> Self {
-        Self { std, min_value }
+        Self {
+            std,
+            min_value,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<T, B> LogNormal<T, B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(self, boundary_handler: H) -> LogNormal<T, H> {
+        LogNormal {
+            std: self.std,
+            min_value: self.min_value,
+            boundary_handler,
+        }
     }
 }
 
-impl Mutator<BoundedValue<f64>> for LogNormal<f64> {
+impl<B> Mutator<BoundedValue<f64>> for LogNormal<f64, B>
+where
+    B: BoundaryHandler,
+{
     fn mutate<'a, R: Rng + ?Sized>(
         &self,
         rng: &mut R,
         parameter: &'a mut BoundedValue<f64>,
     ) -> &'a mut BoundedValue<f64> {
         let distribution = Normal::new(0.0, 1.0).unwrap();
-        let min_value = f64::max(self.min_value, parameter.min_value);
-        let max_value = f64::min(f64::INFINITY, parameter.max_value);
-        parameter.value *= f64::exp(self.std * rng.sample(distribution));
-        parameter.value = parameter.value.clamp(min_value, max_value);
+        let bounds = Bounds {
+            min_value: Some(f64::max(self.min_value, parameter.min_value)),
+            max_value: Some(parameter.max_value),
+        };
+
+        let parent = parameter.value;
+        let sample = |rng: &mut R| parent * f64::exp(self.std * rng.sample(distribution));
+        let mutated = sample(rng);
+        parameter.value = self
+            .boundary_handler
+            .repair(rng, mutated, parent, &bounds, sample);
 
         parameter
     }
 }
 
-pub struct SelfAdaptiveGaussianVectorMutator<T, SM, S>
+/// Log-normal self-adaptation of n step sizes, multiplying every step size by
+/// `exp(tau_prime * N(0, 1) + tau * N_i(0, 1))` where the first sample is shared by all genes.
+///
+/// Step sizes are kept above `min_value` and within the bounds of each step size.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UncorrelatedLogNormal<B = Clamp> {
+    tau: f64,
+    tau_prime: f64,
+    min_value: f64,
+    boundary_handler: B,
+}
+
+impl UncorrelatedLogNormal {
+    pub fn new(tau: f64, tau_prime: f64, min_value: f64) -> Self {
+        Self {
+            tau,
+            tau_prime,
+            min_value,
+            boundary_handler: Clamp,
+        }
+    }
+
+    /// Uses the recommended learning rates `tau = 1 / sqrt(2 sqrt(n))` and
+    /// `tau_prime = 1 / sqrt(2n)` for `n` genes.
+    pub fn with_dimension(dimension: usize, min_value: f64) -> Self {
+        let n = dimension as f64;
+        Self::new(
+            1.0 / (2.0 * n.sqrt()).sqrt(),
+            1.0 / (2.0 * n).sqrt(),
+            min_value,
+        )
+    }
+}
+
+impl<B> UncorrelatedLogNormal<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> UncorrelatedLogNormal<H> {
+        UncorrelatedLogNormal {
+            tau: self.tau,
+            tau_prime: self.tau_prime,
+            min_value: self.min_value,
+            boundary_handler,
+        }
+    }
+}
+
+impl<B> Mutator<BoundedVector<f64>> for UncorrelatedLogNormal<B>
+where
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BoundedVector<f64>,
+    ) -> &'a mut BoundedVector<f64> {
+        let distribution = Normal::new(0.0, 1.0).unwrap();
+        let global_step = self.tau_prime * rng.sample(distribution);
+
+        parameter
+            .value
+            .iter_mut()
+            .zip(parameter.bounds.iter())
+            .for_each(|(value, bounds)| {
+                let bounds = Bounds {
+                    min_value: Some(bounds.min_value.map_or(self.min_value, |min_value| {
+                        f64::max(self.min_value, min_value)
+                    })),
+                    max_value: bounds.max_value,
+                };
+
+                let parent = *value;
+                let sample = |rng: &mut R| {
+                    parent * f64::exp(global_step + self.tau * rng.sample(distribution))
+                };
+                let mutated = sample(rng);
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutated, parent, &bounds, sample);
+            });
+
+        parameter
+    }
+}
+
+/// Self-adaptation of correlated mutations, mutating the step sizes as [`UncorrelatedLogNormal`]
+/// and adding `beta * N(0, 1)` to every rotation angle.
+///
+/// Rotation angles are wrapped around to stay within `[-pi, pi]`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrelatedLogNormal<B = Clamp> {
+    step_size_mutator: UncorrelatedLogNormal<B>,
+    beta: f64,
+}
+
+impl CorrelatedLogNormal {
+    pub fn new(tau: f64, tau_prime: f64, beta: f64, min_value: f64) -> Self {
+        Self {
+            step_size_mutator: UncorrelatedLogNormal::new(tau, tau_prime, min_value),
+            beta,
+        }
+    }
+
+    /// Uses the learning rates of [`UncorrelatedLogNormal::with_dimension`] and the recommended
+    /// `beta` of 5 degrees.
+    pub fn with_dimension(dimension: usize, min_value: f64) -> Self {
+        Self {
+            step_size_mutator: UncorrelatedLogNormal::with_dimension(dimension, min_value),
+            beta: 5.0_f64.to_radians(),
+        }
+    }
+}
+
+impl<B> CorrelatedLogNormal<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> CorrelatedLogNormal<H> {
+        CorrelatedLogNormal {
+            step_size_mutator: self
+                .step_size_mutator
+                .with_boundary_handler(boundary_handler),
+            beta: self.beta,
+        }
+    }
+}
+
+impl<B> Mutator<CorrelatedStrategyParameter> for CorrelatedLogNormal<B>
+where
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut CorrelatedStrategyParameter,
+    ) -> &'a mut CorrelatedStrategyParameter {
+        self.step_size_mutator
+            .mutate(rng, &mut parameter.step_sizes);
+
+        let distribution = Normal::new(0.0, self.beta).unwrap();
+        parameter.rotation_angles.iter_mut().for_each(|angle| {
+            *angle = (*angle + rng.sample(distribution) + PI).rem_euclid(2.0 * PI) - PI;
+        });
+
+        parameter
+    }
+}
+
+/// Mutates the strategy parameter first and then the object variables with the mutated step sizes.
+///
+/// Object variables leaving their bounds are repaired with the given boundary handler, by default
+/// clamping them.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptiveGaussianVectorMutator<T, SM, S, B = Clamp>
 where
     T: PartialOrd,
     SM: Mutator<S>,
     S: GaussianStrategyParameter,
 {
     strategy_mutator: SM,
+    boundary_handler: B,
     _markers: PhantomData<(T, S)>,
 }
 
-impl<SM> Mutator<SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>>
-    for SelfAdaptiveGaussianVectorMutator<f64, SM, BoundedValue<f64>>
+impl<T, SM, S> SelfAdaptiveGaussianVectorMutator<T, SM, S>
+where
+    T: PartialOrd,
+    SM: Mutator<S>,
+    S: GaussianStrategyParameter,
+{
+    pub fn new(strategy_mutator: SM) -> Self {
+        Self {
+            strategy_mutator,
+            boundary_handler: Clamp,
+            _markers: PhantomData,
+        }
+    }
+}
+
+impl<T, SM, S, B> SelfAdaptiveGaussianVectorMutator<T, SM, S, B>
+where
+    T: PartialOrd,
+    SM: Mutator<S>,
+    S: GaussianStrategyParameter,
+{
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> SelfAdaptiveGaussianVectorMutator<T, SM, S, H> {
+        SelfAdaptiveGaussianVectorMutator {
+            strategy_mutator: self.strategy_mutator,
+            boundary_handler,
+            _markers: PhantomData,
+        }
+    }
+}
+
+impl<SM, B> Mutator<SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>>
+    for SelfAdaptiveGaussianVectorMutator<f64, SM, BoundedValue<f64>, B>
 where
     SM: Mutator<BoundedValue<f64>>,
+    B: BoundaryHandler + Clone,
 {
     fn mutate<'a, R: Rng + ?Sized>(
         &self,
@@ -123,8 +640,310 @@ where
     ) -> &'a mut SelfAdaptiveGaussianVector<f64, BoundedValue<f64>> {
         self.strategy_mutator
             .mutate(rng, &mut parameter.strategy_parameter);
-        SimpleGaussian::new(parameter.strategy_parameter.value).mutate(rng, &mut parameter.value);
+        SimpleGaussian::new(parameter.strategy_parameter.value)
+            .with_boundary_handler(self.boundary_handler.clone())
+            .mutate(rng, &mut parameter.value);
+
+        parameter
+    }
+}
+
+impl<SM, B> Mutator<SelfAdaptiveGaussianVector<f64, BoundedVector<f64>>>
+    for SelfAdaptiveGaussianVectorMutator<f64, SM, BoundedVector<f64>, B>
+where
+    SM: Mutator<BoundedVector<f64>>,
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut SelfAdaptiveGaussianVector<f64, BoundedVector<f64>>,
+    ) -> &'a mut SelfAdaptiveGaussianVector<f64, BoundedVector<f64>> {
+        assert_eq!(
+            parameter.value.value.len(),
+            parameter.strategy_parameter.value.len(),
+            "The strategy parameter should contain a step size for every gene"
+        );
+
+        self.strategy_mutator
+            .mutate(rng, &mut parameter.strategy_parameter);
+
+        let distribution = Normal::new(0.0, 1.0).unwrap();
+        let value = &mut parameter.value;
+        value
+            .value
+            .iter_mut()
+            .zip(value.bounds.iter())
+            .zip(parameter.strategy_parameter.value.iter())
+            .for_each(|((value, bounds), std)| {
+                let parent = *value;
+                let sample = |rng: &mut R| parent + std * rng.sample(distribution);
+                let mutated = sample(rng);
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutated, parent, bounds, sample);
+            });
 
         parameter
     }
 }
+
+impl<SM, B> Mutator<SelfAdaptiveGaussianVector<f64, CorrelatedStrategyParameter>>
+    for SelfAdaptiveGaussianVectorMutator<f64, SM, CorrelatedStrategyParameter, B>
+where
+    SM: Mutator<CorrelatedStrategyParameter>,
+    B: BoundaryHandler,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut SelfAdaptiveGaussianVector<f64, CorrelatedStrategyParameter>,
+    ) -> &'a mut SelfAdaptiveGaussianVector<f64, CorrelatedStrategyParameter> {
+        assert_eq!(
+            parameter.value.value.len(),
+            parameter.strategy_parameter.step_sizes.value.len(),
+            "The strategy parameter should contain a step size for every gene"
+        );
+
+        self.strategy_mutator
+            .mutate(rng, &mut parameter.strategy_parameter);
+
+        let strategy_parameter = &parameter.strategy_parameter;
+        let parents = parameter.value.value.clone();
+        let sample_vector = |rng: &mut R| -> Vec<f64> {
+            sample_rotated_gaussian(
+                rng,
+                &parents,
+                &strategy_parameter.step_sizes.value,
+                &strategy_parameter.rotation_angles,
+            )
+        };
+
+        // Resampling a violating gene draws it from its marginal of the rotated distribution
+        let mutated = sample_vector(rng);
+        parameter
+            .value
+            .value
+            .iter_mut()
+            .zip(parameter.value.bounds.iter())
+            .enumerate()
+            .for_each(|(i, (value, bounds))| {
+                *value = self.boundary_handler.repair(
+                    rng,
+                    mutated[i],
+                    parents[i],
+                    bounds,
+                    |rng: &mut R| sample_vector(rng)[i],
+                );
+            });
+
+        parameter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_per_gene_bounds() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = vec![
+            Bounds::new(0.0, 1.0),
+            Bounds::new(-100.0, 100.0),
+            Bounds {
+                min_value: Some(0.0),
+                max_value: None,
+            },
+        ];
+
+        for _ in 0..100 {
+            let mut parameter = BoundedVector::new(bounds.clone(), vec![0.5, 0.0, 0.0]);
+            SimpleGaussian::new(10.0).mutate(&mut rng, &mut parameter);
+            assert!(parameter
+                .value
+                .iter()
+                .zip(&bounds)
+                .all(|(value, bounds)| bounds.contains(value)));
+        }
+
+        let mut parameter = BoundedVector::new(bounds[..2].to_vec(), vec![0.5, 0.0]);
+        UniformMutator::new(1.0).mutate(&mut rng, &mut parameter);
+        assert!((0.0..=1.0).contains(&parameter.value[0]));
+    }
+
+    #[test]
+    fn test_boundary_handlers() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = Bounds::new(-1.0, 1.0);
+
+        for _ in 0..100 {
+            let mut parameter = BoundedVector::new(vec![bounds; 3], vec![0.9, 0.0, -0.9]);
+            SimpleGaussian::new(1.0)
+                .with_boundary_handler(crate::boundary::Reflect)
+                .mutate(&mut rng, &mut parameter);
+            SimpleGaussian::new(1.0)
+                .with_boundary_handler(crate::boundary::Resample::default())
+                .mutate(&mut rng, &mut parameter);
+            assert!(parameter.value.iter().all(|value| bounds.contains(value)));
+            // Reflection leaves the faces of the box unoccupied
+            assert!(parameter.value.iter().all(|value| value.abs() != 1.0));
+        }
+
+        let mutator = SelfAdaptiveGaussianVectorMutator::new(LogNormal::new(0.5, 1e-3))
+            .with_boundary_handler(crate::boundary::Reflect);
+        for _ in 0..100 {
+            let mut parameter = SelfAdaptiveGaussianVector {
+                value: BoundedVector::new(vec![bounds; 3], vec![0.9, 0.0, -0.9]),
+                strategy_parameter: BoundedValue {
+                    min_value: 0.0,
+                    max_value: 10.0,
+                    value: 5.0,
+                },
+            };
+            mutator.mutate(&mut rng, &mut parameter);
+            assert!(parameter.value.value.iter().all(|value| value.abs() < 1.0));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_mutation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = vec![Bounds::new(0.0, 1.0), Bounds::new(-5.0, 5.0)];
+        let mutator = PolynomialMutation::new(20.0, 1.0);
+
+        let mut changed = 0;
+        for _ in 0..1000 {
+            let mut parameter = BoundedVector::new(bounds.clone(), vec![0.99, 0.0]);
+            mutator.mutate(&mut rng, &mut parameter);
+            assert!(parameter
+                .value
+                .iter()
+                .zip(&bounds)
+                .all(|(value, bounds)| bounds.contains(value)));
+            changed += (parameter.value[1] != 0.0) as usize;
+        }
+        assert_eq!(changed, 1000);
+
+        let bounds = vec![
+            Bounds::unbounded(),
+            Bounds {
+                min_value: Some(0.0),
+                max_value: None,
+            },
+        ];
+        let mutator = mutator.with_step_width(2.0);
+        let mut changed = 0;
+        for _ in 0..1000 {
+            let mut parameter = BoundedVector::new(bounds.clone(), vec![0.0, 0.5]);
+            mutator.mutate(&mut rng, &mut parameter);
+            // The unbounded perturbation stays within one step width
+            assert!(parameter.value[0].abs() < 2.0);
+            assert!((0.0..2.5).contains(&parameter.value[1]));
+            changed += (parameter.value[0] != 0.0) as usize;
+        }
+        assert_eq!(changed, 1000);
+    }
+
+    #[test]
+    fn test_heavy_tailed_mutation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let unbounded = BoundedVector::unbounded(vec![0.0; 10000]);
+
+        let mut gaussian = unbounded.clone();
+        SimpleGaussian::new(1.0).mutate(&mut rng, &mut gaussian);
+        let mut cauchy = unbounded.clone();
+        CauchyMutation::new(1.0).mutate(&mut rng, &mut cauchy);
+        let mut levy = unbounded.clone();
+        LevyMutation::new(1.5, 1.0).mutate(&mut rng, &mut levy);
+
+        let long_jumps = |parameter: &BoundedVector<f64>| {
+            parameter.value.iter().filter(|x| x.abs() > 10.0).count()
+        };
+        assert_eq!(long_jumps(&gaussian), 0);
+        assert!(long_jumps(&cauchy) > long_jumps(&levy));
+        assert!(long_jumps(&levy) > 0);
+
+        let mut bounded = BoundedVector::with_shared_bounds(-1.0, 1.0, vec![0.0; 100]);
+        LevyMutation::new(1.0, 1.0)
+            .with_boundary_handler(crate::boundary::Reflect)
+            .mutate(&mut rng, &mut bounded);
+        assert!(bounded.value.iter().all(|x| x.abs() <= 1.0));
+    }
+
+    #[test]
+    fn test_uncorrelated_self_adaptation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut parameter = SelfAdaptiveGaussianVector {
+            value: BoundedVector::with_shared_bounds(-5.0, 5.0, vec![0.0; 5]),
+            strategy_parameter: BoundedVector::with_shared_bounds(0.0, 1.0, vec![0.5; 5]),
+        };
+        let mutator =
+            SelfAdaptiveGaussianVectorMutator::new(UncorrelatedLogNormal::with_dimension(5, 1e-3));
+
+        let mut step_sizes_differ = false;
+        for _ in 0..100 {
+            mutator.mutate(&mut rng, &mut parameter);
+            let step_sizes = &parameter.strategy_parameter.value;
+            assert!(step_sizes.iter().all(|x| (1e-3..=1.0).contains(x)));
+            assert!(parameter.value.value.iter().all(|x| x.abs() <= 5.0));
+            step_sizes_differ |= step_sizes.windows(2).any(|x| x[0] != x[1]);
+        }
+        assert!(step_sizes_differ);
+
+        let mutator =
+            SelfAdaptiveGaussianVectorMutator::new(UncorrelatedLogNormal::with_dimension(5, 1e-3))
+                .with_boundary_handler(crate::boundary::Reflect);
+        for _ in 0..100 {
+            let mut parameter = SelfAdaptiveGaussianVector {
+                value: BoundedVector::with_shared_bounds(-1.0, 1.0, vec![0.9; 5]),
+                strategy_parameter: BoundedVector::with_shared_bounds(0.0, 10.0, vec![5.0; 5]),
+            };
+            mutator.mutate(&mut rng, &mut parameter);
+            assert!(parameter.value.value.iter().all(|x| x.abs() < 1.0));
+        }
+    }
+
+    #[test]
+    fn test_correlated_self_adaptation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let step_sizes = BoundedVector::with_shared_bounds(0.0, 1.0, vec![0.5; 4]);
+        let mut parameter = SelfAdaptiveGaussianVector {
+            value: BoundedVector::with_shared_bounds(-5.0, 5.0, vec![0.0; 4]),
+            strategy_parameter: CorrelatedStrategyParameter::uncorrelated(step_sizes),
+        };
+        let mutator =
+            SelfAdaptiveGaussianVectorMutator::new(CorrelatedLogNormal::new(0.3, 0.2, 1.0, 1e-3));
+
+        for _ in 0..100 {
+            mutator.mutate(&mut rng, &mut parameter);
+            let strategy_parameter = &parameter.strategy_parameter;
+            assert_eq!(strategy_parameter.rotation_angles.len(), 6);
+            assert!(strategy_parameter
+                .rotation_angles
+                .iter()
+                .all(|angle| angle.abs() <= PI));
+            assert!(strategy_parameter
+                .step_sizes
+                .value
+                .iter()
+                .all(|x| (1e-3..=1.0).contains(x)));
+            assert!(parameter.value.value.iter().all(|x| x.abs() <= 5.0));
+        }
+
+        let mutator =
+            SelfAdaptiveGaussianVectorMutator::new(CorrelatedLogNormal::new(0.3, 0.2, 1.0, 1e-3))
+                .with_boundary_handler(crate::boundary::Reflect);
+        for _ in 0..100 {
+            let step_sizes = BoundedVector::with_shared_bounds(0.0, 10.0, vec![5.0; 4]);
+            let mut parameter = SelfAdaptiveGaussianVector {
+                value: BoundedVector::with_shared_bounds(-1.0, 1.0, vec![0.9; 4]),
+                strategy_parameter: CorrelatedStrategyParameter::new(step_sizes, vec![0.5; 6]),
+            };
+            mutator.mutate(&mut rng, &mut parameter);
+            assert!(parameter.value.value.iter().all(|x| x.abs() < 1.0));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use rand::Rng;
+use rand_distr::Bernoulli;
+
+use super::Mutator;
+use crate::parameter::BitString;
+
+/// Flips every bit independently with the given probability.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitFlip {
+    probability: f64,
+}
+
+impl BitFlip {
+    pub fn new(probability: f64) -> Self {
+        Self {
+            probability: probability.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Mutator<BitString> for BitFlip {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut BitString,
+    ) -> &'a mut BitString {
+        let distribution = Bernoulli::new(self.probability).unwrap();
+        parameter
+            .value
+            .iter_mut()
+            .zip(rng.sample_iter(distribution))
+            .for_each(|(bit, flip)| *bit ^= flip);
+        parameter
+    }
+}
@@ -3,7 +3,9 @@ use rand_distr::Bernoulli;
 use rand_distr::Uniform;
 
 use super::Mutator;
+use crate::parameter::BoundedVector;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitFlip<T>
 where
     T: PartialOrd,
@@ -28,6 +30,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RandomResetting<T> {
     probability: f64,
     min_value: T,
@@ -44,6 +47,7 @@ impl<T> RandomResetting<T> {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleCreep<T> {
     probability: f64,
     min_value: T,
@@ -62,6 +66,38 @@ impl<T> SimpleCreep<T> {
     }
 }
 
+/// Resets every gene of an integer vector with the given probability to a uniformly sampled value
+/// within the bounds of that gene.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenewiseRandomResetting {
+    probability: f64,
+}
+
+impl GenewiseRandomResetting {
+    pub fn new(probability: f64) -> Self {
+        Self {
+            probability: probability.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Moves every gene of an integer vector with the given probability up or down by the step size,
+/// clamped to the bounds of that gene.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenewiseCreep<T> {
+    probability: f64,
+    step_size: T,
+}
+
+impl<T> GenewiseCreep<T> {
+    pub fn new(probability: f64, step_size: T) -> Self {
+        Self {
+            probability: probability.clamp(0.0, 1.0),
+            step_size,
+        }
+    }
+}
+
 macro_rules! int_mutator_impl {
     ($($Int:ty)+) => {
         $(
@@ -106,6 +142,37 @@ macro_rules! int_mutator_impl {
                     parameter
                 }
             }
+
+            impl Mutator<BoundedVector<$Int>> for GenewiseRandomResetting {
+                fn mutate<'a, R: Rng + ?Sized>(&self, rng: &mut R, parameter: &'a mut BoundedVector<$Int>) -> &'a mut BoundedVector<$Int> {
+                    let distribution = Bernoulli::new(self.probability).unwrap();
+                    for (value, bounds) in parameter.value.iter_mut().zip(parameter.bounds.iter()) {
+                        if rng.sample(distribution) {
+                            let (min_value, max_value) = bounds
+                                .range()
+                                .expect("Random resetting requires finite bounds for every gene");
+                            *value = rng.sample(Uniform::new_inclusive(min_value, max_value));
+                        }
+                    }
+                    parameter
+                }
+            }
+
+            impl Mutator<BoundedVector<$Int>> for GenewiseCreep<$Int> {
+                fn mutate<'a, R: Rng + ?Sized>(&self, rng: &mut R, parameter: &'a mut BoundedVector<$Int>) -> &'a mut BoundedVector<$Int> {
+                    let distribution = Bernoulli::new(self.probability).unwrap();
+                    for (value, bounds) in parameter.value.iter_mut().zip(parameter.bounds.iter()) {
+                        if rng.sample(distribution) {
+                            let moved = match rng.gen_bool(0.5) {
+                                true => value.saturating_add(self.step_size),
+                                false => value.saturating_sub(self.step_size),
+                            };
+                            *value = bounds.clamp(moved);
+                        }
+                    }
+                    parameter
+                }
+            }
         )+
     };
 }
@@ -117,6 +184,7 @@ mod tests {
     use rand::{rngs::StdRng, SeedableRng};
 
     use super::*;
+    use crate::parameter::Bounds;
 
     #[test]
     fn test_random_resetting() {
@@ -135,4 +203,21 @@ mod tests {
         mutator.mutate(&mut rng, &mut parameter);
         assert!((0..=10).contains(&parameter));
     }
+
+    #[test]
+    fn test_genewise_mutators() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = vec![Bounds::new(0, 1), Bounds::new(-10, 10), Bounds::new(5, 5)];
+        let mut parameter = BoundedVector::new(bounds.clone(), vec![0, 0, 5]);
+
+        for _ in 0..100 {
+            GenewiseRandomResetting::new(0.5).mutate(&mut rng, &mut parameter);
+            GenewiseCreep::new(0.5, 3).mutate(&mut rng, &mut parameter);
+            assert!(parameter
+                .value
+                .iter()
+                .zip(&bounds)
+                .all(|(value, bounds)| bounds.contains(value)));
+        }
+    }
 }
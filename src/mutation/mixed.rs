@@ -0,0 +1,132 @@
+use rand::Rng;
+use rand_distr::{Bernoulli, Uniform};
+
+use super::Mutator;
+use crate::parameter::{BoundedValue, Categorical, MixedGene, MixedVector, Ordinal};
+
+/// Moves an ordinal gene to one of its adjacent levels.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrdinalCreep {
+    probability: f64,
+}
+
+impl OrdinalCreep {
+    pub fn new(probability: f64) -> Self {
+        Self {
+            probability: probability.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Mutator<Ordinal> for OrdinalCreep {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Ordinal,
+    ) -> &'a mut Ordinal {
+        if parameter.levels > 1 && rng.sample(Bernoulli::new(self.probability).unwrap()) {
+            parameter.value = match parameter.value {
+                0 => 1,
+                value if value == parameter.levels - 1 => value - 1,
+                value if rng.gen_bool(0.5) => value + 1,
+                value => value - 1,
+            };
+        }
+        parameter
+    }
+}
+
+/// Replaces a categorical gene by a different category, chosen uniformly.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CategoricalResetting {
+    probability: f64,
+}
+
+impl CategoricalResetting {
+    pub fn new(probability: f64) -> Self {
+        Self {
+            probability: probability.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Mutator<Categorical> for CategoricalResetting {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Categorical,
+    ) -> &'a mut Categorical {
+        if parameter.categories > 1 && rng.sample(Bernoulli::new(self.probability).unwrap()) {
+            // Skip over the current category to always pick a different one
+            let category = rng.sample(Uniform::new(0, parameter.categories - 1));
+            parameter.value = match category >= parameter.value {
+                true => category + 1,
+                false => category,
+            };
+        }
+        parameter
+    }
+}
+
+/// Mutates every gene of a `MixedVector` with the mutator belonging to its kind.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixedVectorMutator<RM, IM, OM, CM> {
+    real_mutator: RM,
+    integer_mutator: IM,
+    ordinal_mutator: OM,
+    categorical_mutator: CM,
+}
+
+impl<RM, IM, OM, CM> MixedVectorMutator<RM, IM, OM, CM>
+where
+    RM: Mutator<BoundedValue<f64>>,
+    IM: Mutator<BoundedValue<i64>>,
+    OM: Mutator<Ordinal>,
+    CM: Mutator<Categorical>,
+{
+    pub fn new(
+        real_mutator: RM,
+        integer_mutator: IM,
+        ordinal_mutator: OM,
+        categorical_mutator: CM,
+    ) -> Self {
+        Self {
+            real_mutator,
+            integer_mutator,
+            ordinal_mutator,
+            categorical_mutator,
+        }
+    }
+}
+
+impl<RM, IM, OM, CM> Mutator<MixedVector> for MixedVectorMutator<RM, IM, OM, CM>
+where
+    RM: Mutator<BoundedValue<f64>>,
+    IM: Mutator<BoundedValue<i64>>,
+    OM: Mutator<Ordinal>,
+    CM: Mutator<Categorical>,
+{
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut MixedVector,
+    ) -> &'a mut MixedVector {
+        for gene in parameter.genes.iter_mut() {
+            match gene {
+                MixedGene::Real(value) => {
+                    self.real_mutator.mutate(rng, value);
+                }
+                MixedGene::Integer(value) => {
+                    self.integer_mutator.mutate(rng, value);
+                }
+                MixedGene::Ordinal(value) => {
+                    self.ordinal_mutator.mutate(rng, value);
+                }
+                MixedGene::Categorical(value) => {
+                    self.categorical_mutator.mutate(rng, value);
+                }
+            }
+        }
+        parameter
+    }
+}
@@ -0,0 +1,111 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::Mutator;
+use crate::parameter::Permutation;
+
+/// Exchanges the positions of two randomly chosen elements.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Swap;
+
+/// Moves a randomly chosen element next to another randomly chosen element.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Insert;
+
+/// Randomly reorders the elements of a random segment.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scramble;
+
+/// Reverses the order of the elements of a random segment.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inversion;
+
+/// Two distinct positions in ascending order.
+fn sample_positions<R: Rng + ?Sized>(rng: &mut R, length: usize) -> (usize, usize) {
+    assert!(
+        length >= 2,
+        "The permutation should contain at least two elements"
+    );
+
+    let positions = rand::seq::index::sample(rng, length, 2);
+    let (a, b) = (positions.index(0), positions.index(1));
+    (usize::min(a, b), usize::max(a, b))
+}
+
+impl Mutator<Permutation> for Swap {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Permutation,
+    ) -> &'a mut Permutation {
+        let (a, b) = sample_positions(rng, parameter.len());
+        parameter.value.swap(a, b);
+        parameter
+    }
+}
+
+impl Mutator<Permutation> for Insert {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Permutation,
+    ) -> &'a mut Permutation {
+        let (a, b) = sample_positions(rng, parameter.len());
+        // Shift the segment in between, such that the second element follows the first
+        parameter.value[a + 1..=b].rotate_right(1);
+        parameter
+    }
+}
+
+impl Mutator<Permutation> for Scramble {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Permutation,
+    ) -> &'a mut Permutation {
+        let (a, b) = sample_positions(rng, parameter.len());
+        parameter.value[a..=b].shuffle(rng);
+        parameter
+    }
+}
+
+impl Mutator<Permutation> for Inversion {
+    fn mutate<'a, R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parameter: &'a mut Permutation,
+    ) -> &'a mut Permutation {
+        let (a, b) = sample_positions(rng, parameter.len());
+        parameter.value[a..=b].reverse();
+        parameter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_mutators_preserve_permutation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        for _ in 0..100 {
+            let mut permutation = Permutation::random(&mut rng, 10);
+            Swap.mutate(&mut rng, &mut permutation);
+            Insert.mutate(&mut rng, &mut permutation);
+            Scramble.mutate(&mut rng, &mut permutation);
+            Inversion.mutate(&mut rng, &mut permutation);
+
+            Permutation::new(permutation.value);
+        }
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut permutation = Permutation::identity(2);
+        Insert.mutate(&mut rng, &mut permutation);
+        assert_eq!(permutation.value, vec![0, 1]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{individual::Individual, population::Population, solver::RunState};
+
+/// Hooks invoked by a solver at well-defined points of a run.
+pub trait Observer<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn on_initialization(&mut self, _population: &Population<I, F>, _state: &RunState) {}
+
+    fn on_parent_selection(
+        &mut self,
+        _population: &Population<I, F>,
+        _mating_pool: &[&I],
+        _state: &RunState,
+    ) {
+    }
+
+    fn on_offspring_evaluation(
+        &mut self,
+        _population: &Population<I, F>,
+        _offspring: &[I],
+        _state: &RunState,
+    ) {
+    }
+
+    fn on_survivor_selection(&mut self, _population: &Population<I, F>, _state: &RunState) {}
+
+    fn on_termination(&mut self, _population: &Population<I, F>, _state: &RunState) {}
+
+    /// Requests the solver to stop after the current generation.
+    fn should_stop(&self) -> bool {
+        false
+    }
+}
+
+impl<I, F> Observer<I, F> for ()
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+}
+
+impl<I, F, O> Observer<I, F> for &mut O
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    O: Observer<I, F> + ?Sized,
+{
+    fn on_initialization(&mut self, population: &Population<I, F>, state: &RunState) {
+        O::on_initialization(self, population, state);
+    }
+
+    fn on_parent_selection(
+        &mut self,
+        population: &Population<I, F>,
+        mating_pool: &[&I],
+        state: &RunState,
+    ) {
+        O::on_parent_selection(self, population, mating_pool, state);
+    }
+
+    fn on_offspring_evaluation(
+        &mut self,
+        population: &Population<I, F>,
+        offspring: &[I],
+        state: &RunState,
+    ) {
+        O::on_offspring_evaluation(self, population, offspring, state);
+    }
+
+    fn on_survivor_selection(&mut self, population: &Population<I, F>, state: &RunState) {
+        O::on_survivor_selection(self, population, state);
+    }
+
+    fn on_termination(&mut self, population: &Population<I, F>, state: &RunState) {
+        O::on_termination(self, population, state);
+    }
+
+    fn should_stop(&self) -> bool {
+        O::should_stop(self)
+    }
+}
+
+impl<I, F, A, B> Observer<I, F> for (A, B)
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    A: Observer<I, F>,
+    B: Observer<I, F>,
+{
+    fn on_initialization(&mut self, population: &Population<I, F>, state: &RunState) {
+        self.0.on_initialization(population, state);
+        self.1.on_initialization(population, state);
+    }
+
+    fn on_parent_selection(
+        &mut self,
+        population: &Population<I, F>,
+        mating_pool: &[&I],
+        state: &RunState,
+    ) {
+        self.0.on_parent_selection(population, mating_pool, state);
+        self.1.on_parent_selection(population, mating_pool, state);
+    }
+
+    fn on_offspring_evaluation(
+        &mut self,
+        population: &Population<I, F>,
+        offspring: &[I],
+        state: &RunState,
+    ) {
+        self.0.on_offspring_evaluation(population, offspring, state);
+        self.1.on_offspring_evaluation(population, offspring, state);
+    }
+
+    fn on_survivor_selection(&mut self, population: &Population<I, F>, state: &RunState) {
+        self.0.on_survivor_selection(population, state);
+        self.1.on_survivor_selection(population, state);
+    }
+
+    fn on_termination(&mut self, population: &Population<I, F>, state: &RunState) {
+        self.0.on_termination(population, state);
+        self.1.on_termination(population, state);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.0.should_stop() || self.1.should_stop()
+    }
+}
@@ -1,18 +1,127 @@
+use rand::{seq::SliceRandom, Rng};
+
 pub trait Parameter {}
 
+/// Inclusive bounds of a single gene, where a missing bound leaves that side unbounded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds<T>
+where
+    T: PartialOrd,
+{
+    pub min_value: Option<T>,
+    pub max_value: Option<T>,
+}
+
+impl<T> Bounds<T>
+where
+    T: PartialOrd + Copy,
+{
+    pub fn new(min_value: T, max_value: T) -> Self {
+        assert!(
+            min_value <= max_value,
+            "The minimum value should not exceed the maximum value"
+        );
+
+        Self {
+            min_value: Some(min_value),
+            max_value: Some(max_value),
+        }
+    }
+
+    pub fn unbounded() -> Self {
+        Self {
+            min_value: None,
+            max_value: None,
+        }
+    }
+
+    /// Finite range of the gene, if bounded on both sides.
+    pub fn range(&self) -> Option<(T, T)> {
+        self.min_value.zip(self.max_value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let above_min = match self.min_value {
+            Some(min_value) => min_value <= *value,
+            None => true,
+        };
+        let below_max = match self.max_value {
+            Some(max_value) => *value <= max_value,
+            None => true,
+        };
+        above_min && below_max
+    }
+
+    pub fn clamp(&self, value: T) -> T {
+        match (self.min_value, self.max_value) {
+            (Some(min_value), _) if value < min_value => min_value,
+            (_, Some(max_value)) if value > max_value => max_value,
+            _ => value,
+        }
+    }
+}
+
+impl Bounds<f64> {
+    /// Distance between the bounds, infinite for unbounded genes.
+    pub fn width(&self) -> f64 {
+        self.max_value.unwrap_or(f64::INFINITY) - self.min_value.unwrap_or(f64::NEG_INFINITY)
+    }
+}
+
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoundedVector<T>
 where
     T: PartialOrd,
 {
-    pub min_value: T,
-    pub max_value: T,
+    pub bounds: Vec<Bounds<T>>,
     pub value: Vec<T>,
 }
 
+impl<T> BoundedVector<T>
+where
+    T: PartialOrd + Copy,
+{
+    pub fn new(bounds: Vec<Bounds<T>>, value: Vec<T>) -> Self {
+        assert_eq!(
+            bounds.len(),
+            value.len(),
+            "Every gene of the vector should have its own bounds"
+        );
+
+        Self { bounds, value }
+    }
+
+    /// Vector of which every gene shares the same bounds.
+    pub fn with_shared_bounds(min_value: T, max_value: T, value: Vec<T>) -> Self {
+        Self {
+            bounds: vec![Bounds::new(min_value, max_value); value.len()],
+            value,
+        }
+    }
+
+    pub fn unbounded(value: Vec<T>) -> Self {
+        Self {
+            bounds: vec![Bounds::unbounded(); value.len()],
+            value,
+        }
+    }
+
+    /// Clamps every gene to its bounds.
+    pub fn clamp(&mut self) -> &mut Self {
+        self.value
+            .iter_mut()
+            .zip(self.bounds.iter())
+            .for_each(|(value, bounds)| *value = bounds.clamp(*value));
+        self
+    }
+}
+
 impl<T> Parameter for BoundedVector<T> where T: PartialOrd {}
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoundedValue<T>
 where
     T: PartialOrd,
@@ -26,6 +135,46 @@ pub trait GaussianStrategyParameter {}
 
 impl GaussianStrategyParameter for BoundedValue<f64> {}
 
+/// One step size per gene, for uncorrelated self-adaptive mutation with n step sizes.
+impl GaussianStrategyParameter for BoundedVector<f64> {}
+
+/// Step sizes of every gene together with the rotation angles between every pair of genes, for
+/// correlated self-adaptive mutation.
+///
+/// The angle of genes `i < j` is stored in lexicographic order of `(i, j)`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrelatedStrategyParameter {
+    pub step_sizes: BoundedVector<f64>,
+    pub rotation_angles: Vec<f64>,
+}
+
+impl CorrelatedStrategyParameter {
+    pub fn new(step_sizes: BoundedVector<f64>, rotation_angles: Vec<f64>) -> Self {
+        let n = step_sizes.value.len();
+        assert_eq!(
+            rotation_angles.len(),
+            n * n.saturating_sub(1) / 2,
+            "There should be a rotation angle for every pair of genes"
+        );
+
+        Self {
+            step_sizes,
+            rotation_angles,
+        }
+    }
+
+    /// Starts without any correlation between the genes.
+    pub fn uncorrelated(step_sizes: BoundedVector<f64>) -> Self {
+        let n = step_sizes.value.len();
+        Self::new(step_sizes, vec![0.0; n * n.saturating_sub(1) / 2])
+    }
+}
+
+impl GaussianStrategyParameter for CorrelatedStrategyParameter {}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfAdaptiveGaussianVector<T, S>
 where
     T: PartialOrd,
@@ -34,3 +183,247 @@ where
     pub value: BoundedVector<T>,
     pub strategy_parameter: S,
 }
+
+/// Ordering of the integers `0..n`, as used for routing and scheduling problems.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Permutation {
+    pub value: Vec<usize>,
+}
+
+impl Permutation {
+    pub fn new(value: Vec<usize>) -> Self {
+        let mut seen = vec![false; value.len()];
+        for &x in value.iter() {
+            assert!(
+                x < value.len() && !seen[x],
+                "The value should contain every integer from 0 up to its length exactly once"
+            );
+            seen[x] = true;
+        }
+
+        Self { value }
+    }
+
+    pub fn identity(length: usize) -> Self {
+        Self {
+            value: (0..length).collect(),
+        }
+    }
+
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, length: usize) -> Self {
+        let mut permutation = Self::identity(length);
+        permutation.value.shuffle(rng);
+        permutation
+    }
+
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+impl Parameter for Permutation {}
+
+/// Index into an ordered set of `levels` values, such as a number of layers from a fixed list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ordinal {
+    pub value: usize,
+    pub levels: usize,
+}
+
+impl Ordinal {
+    pub fn new(value: usize, levels: usize) -> Self {
+        assert!(
+            value < levels,
+            "The value should be less than the number of levels"
+        );
+        Self { value, levels }
+    }
+}
+
+/// Index into an unordered set of `categories` values, such as the name of an activation function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Categorical {
+    pub value: usize,
+    pub categories: usize,
+}
+
+impl Categorical {
+    pub fn new(value: usize, categories: usize) -> Self {
+        assert!(
+            value < categories,
+            "The value should be less than the number of categories"
+        );
+        Self { value, categories }
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MixedGene {
+    Real(BoundedValue<f64>),
+    Integer(BoundedValue<i64>),
+    Ordinal(Ordinal),
+    Categorical(Categorical),
+}
+
+/// Genome of which every gene may be of a different kind.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixedVector {
+    pub genes: Vec<MixedGene>,
+}
+
+impl MixedVector {
+    pub fn new(genes: Vec<MixedGene>) -> Self {
+        Self { genes }
+    }
+}
+
+impl Parameter for MixedVector {}
+
+/// String of bits of arbitrary length.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitString {
+    pub value: Vec<bool>,
+}
+
+impl BitString {
+    pub fn new(value: Vec<bool>) -> Self {
+        Self { value }
+    }
+
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, length: usize) -> Self {
+        Self {
+            value: (0..length).map(|_| rng.gen()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.value.iter().filter(|&&bit| bit).count()
+    }
+}
+
+impl Parameter for BitString {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryEncoding {
+    /// Standard positional binary numbers.
+    Binary,
+    /// Reflected binary code, where adjacent numbers differ by a single bit.
+    Gray,
+}
+
+/// Decodes consecutive fixed-width segments of a `BitString`, most significant bit first, into
+/// integer or real phenotypes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitStringDecoder {
+    encoding: BinaryEncoding,
+    bits_per_value: usize,
+}
+
+impl BitStringDecoder {
+    pub fn new(encoding: BinaryEncoding, bits_per_value: usize) -> Self {
+        assert!(
+            (1..=64).contains(&bits_per_value),
+            "The number of bits per value should be between 1 and 64"
+        );
+
+        Self {
+            encoding,
+            bits_per_value,
+        }
+    }
+
+    /// Largest unsigned value a single segment can represent.
+    fn max_unsigned(&self) -> u64 {
+        u64::MAX >> (64 - self.bits_per_value)
+    }
+
+    pub fn decode_unsigned(&self, bits: &BitString) -> Vec<u64> {
+        assert_eq!(
+            bits.len() % self.bits_per_value,
+            0,
+            "The length of the bit string should be a multiple of the number of bits per value"
+        );
+
+        bits.value
+            .chunks_exact(self.bits_per_value)
+            .map(|segment| {
+                let mut previous = false;
+                segment.iter().fold(0, |acc, &bit| {
+                    let bit = match self.encoding {
+                        BinaryEncoding::Binary => bit,
+                        BinaryEncoding::Gray => {
+                            previous ^= bit;
+                            previous
+                        }
+                    };
+                    (acc << 1) | bit as u64
+                })
+            })
+            .collect()
+    }
+
+    /// Maps every segment linearly onto the finite bounds of the corresponding gene.
+    pub fn decode_reals(&self, bits: &BitString, bounds: &[Bounds<f64>]) -> Vec<f64> {
+        let max_unsigned = self.max_unsigned() as f64;
+        self.decode_unsigned_with_bounds(bits, bounds)
+            .map(|(x, (min_value, max_value))| {
+                min_value + (max_value - min_value) * x as f64 / max_unsigned
+            })
+            .collect()
+    }
+
+    /// Maps every segment linearly onto the finite bounds of the corresponding gene, rounded to the
+    /// nearest integer.
+    pub fn decode_integers(&self, bits: &BitString, bounds: &[Bounds<i64>]) -> Vec<i64> {
+        // Exact in 128 bits, as the range of the bounds may exceed i64
+        let max_unsigned = self.max_unsigned() as u128;
+        self.decode_unsigned_with_bounds(bits, bounds)
+            .map(|(x, (min_value, max_value))| {
+                let range = (max_value as i128 - min_value as i128) as u128;
+                let offset = (range * x as u128 + max_unsigned / 2) / max_unsigned;
+                (min_value as i128 + offset as i128) as i64
+            })
+            .collect()
+    }
+
+    fn decode_unsigned_with_bounds<'a, T>(
+        &self,
+        bits: &BitString,
+        bounds: &'a [Bounds<T>],
+    ) -> impl Iterator<Item = (u64, (T, T))> + 'a
+    where
+        T: PartialOrd + Copy,
+    {
+        let values = self.decode_unsigned(bits);
+        assert_eq!(
+            values.len(),
+            bounds.len(),
+            "Every decoded value should have its own bounds"
+        );
+
+        values.into_iter().zip(bounds.iter().map(|bounds| {
+            bounds
+                .range()
+                .expect("Decoding requires finite bounds for every gene")
+        }))
+    }
+}
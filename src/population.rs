@@ -4,16 +4,19 @@ use rand::{seq::SliceRandom, Rng};
 use rand_distr::{uniform::SampleUniform, Uniform};
 
 use crate::{
+    evaluation::Evaluator,
     individual::{BoundedVectorIndividual, Individual},
-    parameter::BoundedVector,
+    parameter::{BoundedVector, Bounds},
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MigrationType {
     Random,
     Best,
     Worst,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Population<I, F>
 where
     I: Individual<F>,
@@ -43,6 +46,18 @@ where
         &mut self.individuals
     }
 
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+
+    pub fn best(&self) -> Option<&I> {
+        self.individuals.iter().max_by(|a, b| a.compare_fitness(b))
+    }
+
     pub fn set_fitnesses(&mut self, fitnesses: &[F]) {
         if fitnesses.len() != self.individuals.len() {
             panic!("Length of fitnesses must be equal to the size of the population.");
@@ -56,6 +71,13 @@ where
             });
     }
 
+    pub fn evaluate<E>(&mut self, evaluator: &E)
+    where
+        E: Evaluator<I, F>,
+    {
+        evaluator.evaluate(&mut self.individuals);
+    }
+
     pub fn increment_ages(&mut self) {
         self.individuals.iter_mut().for_each(|individual| {
             individual.set_age(individual.age() + 1);
@@ -95,10 +117,50 @@ where
     }
 }
 
+impl<T, F> Population<BoundedVectorIndividual<T, F>, F>
+where
+    T: PartialOrd + Copy + Into<f64>,
+    F: PartialOrd + Clone,
+{
+    /// Mean Euclidean distance of the individuals to the centroid of the population.
+    pub fn diversity(&self) -> f64 {
+        let size = self.individuals.len();
+        if size == 0 {
+            return 0.0;
+        }
+
+        let length = self.individuals[0].vector().value.len();
+        let mut centroid = vec![0.0; length];
+        for individual in self.individuals.iter() {
+            centroid
+                .iter_mut()
+                .zip(&individual.vector().value)
+                .for_each(|(c, &x)| *c += x.into() / size as f64);
+        }
+
+        let sum_distances: f64 = self
+            .individuals
+            .iter()
+            .map(|individual| {
+                individual
+                    .vector()
+                    .value
+                    .iter()
+                    .zip(&centroid)
+                    .map(|(&x, c)| (x.into() - c).powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .sum();
+
+        sum_distances / size as f64
+    }
+}
+
 impl<T, F> Population<BoundedVectorIndividual<T, F>, F>
 where
     T: PartialOrd + SampleUniform + Copy,
-    F: PartialOrd + Default + Copy,
+    F: PartialOrd + Default + Clone,
 {
     pub fn new<R: Rng + ?Sized>(
         rng: &mut R,
@@ -107,16 +169,31 @@ where
         length: usize,
         size: usize,
     ) -> Self {
+        Self::new_with_bounds(rng, &vec![Bounds::new(min_value, max_value); length], size)
+    }
+
+    /// Population sampled uniformly within the bounds of every gene, which should all be finite.
+    pub fn new_with_bounds<R: Rng + ?Sized>(
+        rng: &mut R,
+        bounds: &[Bounds<T>],
+        size: usize,
+    ) -> Self {
+        let distributions: Vec<_> = bounds
+            .iter()
+            .map(|bounds| {
+                let (min_value, max_value) = bounds
+                    .range()
+                    .expect("Sampling a population requires finite bounds for every gene");
+                Uniform::new_inclusive(min_value, max_value)
+            })
+            .collect();
+
         let individuals = (0..size)
             .map(|_| {
-                BoundedVectorIndividual::new(BoundedVector {
-                    min_value,
-                    max_value,
-                    value: rng
-                        .sample_iter(Uniform::new_inclusive(min_value, max_value))
-                        .take(length)
-                        .collect(),
-                })
+                BoundedVectorIndividual::new(BoundedVector::new(
+                    bounds.to_vec(),
+                    distributions.iter().map(|x| rng.sample(x)).collect(),
+                ))
             })
             .collect();
 
@@ -0,0 +1,378 @@
+use std::time::Instant;
+
+use rand::Rng;
+use rand_distr::Uniform;
+
+use crate::{
+    evaluation::Evaluator, individual::Individual, observer::Observer, parameter::BoundedVector,
+    population::Population, solver::RunState, termination::Termination,
+};
+
+#[derive(Clone)]
+pub struct Particle<F> {
+    position: BoundedVector<f64>,
+    velocity: Vec<f64>,
+    best_position: Vec<f64>,
+    fitness: F,
+    best_fitness: Option<F>,
+    age: u32,
+}
+
+impl<F> Particle<F>
+where
+    F: PartialOrd + Copy + Default,
+{
+    pub fn new(position: BoundedVector<f64>, velocity: Vec<f64>) -> Self {
+        if position.value.len() != velocity.len() {
+            panic!("Length of the velocity must be equal to the length of the position.");
+        }
+
+        Self {
+            best_position: position.value.clone(),
+            position,
+            velocity,
+            fitness: Default::default(),
+            best_fitness: None,
+            age: 0,
+        }
+    }
+}
+
+impl<F> Particle<F>
+where
+    F: PartialOrd + Copy,
+{
+    pub fn position(&self) -> &BoundedVector<f64> {
+        &self.position
+    }
+
+    pub fn velocity(&self) -> &[f64] {
+        &self.velocity
+    }
+
+    pub fn best_position(&self) -> &[f64] {
+        &self.best_position
+    }
+
+    pub fn best_fitness(&self) -> Option<F> {
+        self.best_fitness
+    }
+
+    fn update_best(&mut self) {
+        let improved = match self.best_fitness {
+            Some(best) => self.fitness > best,
+            None => true,
+        };
+        if improved {
+            self.best_fitness = Some(self.fitness);
+            self.best_position.clone_from(&self.position.value);
+        }
+    }
+}
+
+impl<F> Individual<F> for Particle<F>
+where
+    F: PartialOrd + Copy,
+{
+    fn fitness(&self) -> F {
+        self.fitness
+    }
+
+    fn set_fitness(&mut self, fitness: F) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+
+    fn age(&self) -> u32 {
+        self.age
+    }
+
+    fn set_age(&mut self, age: u32) -> &mut Self {
+        self.age = age;
+        self
+    }
+}
+
+/// Neighborhood from which a particle takes its social attractor.
+pub enum Topology {
+    /// Every particle is informed by the best particle of the swarm.
+    Global,
+    /// Particles are informed by the given amount of neighbors on each side in a ring.
+    Ring { neighbors: usize },
+    /// Particles are informed by their four neighbors on a toroidal grid.
+    VonNeumann,
+}
+
+impl Topology {
+    fn neighborhood(&self, index: usize, size: usize) -> Vec<usize> {
+        match self {
+            Topology::Global => (0..size).collect(),
+            Topology::Ring { neighbors } => {
+                let neighbors = usize::min(*neighbors, size / 2);
+                (size - neighbors..=size + neighbors)
+                    .map(|offset| (index + offset) % size)
+                    .collect()
+            }
+            Topology::VonNeumann => {
+                let columns = usize::max((size as f64).sqrt().floor() as usize, 1);
+                let rows = size.div_ceil(columns);
+                let (row, column) = (index / columns, index % columns);
+
+                [
+                    (row, column),
+                    ((row + rows - 1) % rows, column),
+                    ((row + 1) % rows, column),
+                    (row, (column + columns - 1) % columns),
+                    (row, (column + 1) % columns),
+                ]
+                .into_iter()
+                .map(|(row, column)| row * columns + column)
+                // Cells of an incomplete last row are skipped
+                .filter(|&neighbor| neighbor < size)
+                .collect()
+            }
+        }
+    }
+}
+
+pub enum VelocityUpdate {
+    Inertia {
+        inertia_weight: f64,
+        cognitive: f64,
+        social: f64,
+    },
+    /// Clerc's constriction coefficient, requires `cognitive + social > 4`.
+    Constriction { cognitive: f64, social: f64 },
+}
+
+/// Particle Swarm Optimization on real-valued bounded positions.
+///
+/// Velocities are limited to the width of the bounds and particles leaving the bounds are clamped
+/// with the offending velocity component reset to zero.
+pub struct ParticleSwarm {
+    topology: Topology,
+    velocity_update: VelocityUpdate,
+}
+
+impl ParticleSwarm {
+    pub fn new(topology: Topology, velocity_update: VelocityUpdate) -> Self {
+        if let VelocityUpdate::Constriction { cognitive, social } = velocity_update {
+            assert!(
+                cognitive + social > 4.0,
+                "The sum of the acceleration coefficients must exceed 4 for constriction"
+            );
+        }
+
+        Self {
+            topology,
+            velocity_update,
+        }
+    }
+
+    pub fn new_population<R, F>(
+        &self,
+        rng: &mut R,
+        min_value: f64,
+        max_value: f64,
+        length: usize,
+        size: usize,
+    ) -> Population<Particle<F>, F>
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy + Default,
+    {
+        let distribution = Uniform::new_inclusive(min_value, max_value);
+        let particles = (0..size)
+            .map(|_| {
+                let value: Vec<f64> = rng.sample_iter(distribution).take(length).collect();
+                let velocity = value
+                    .iter()
+                    .map(|x| (rng.sample(distribution) - x) / 2.0)
+                    .collect();
+
+                Particle::new(
+                    BoundedVector::with_shared_bounds(min_value, max_value, value),
+                    velocity,
+                )
+            })
+            .collect();
+
+        Population::new_from_individuals(particles)
+    }
+
+    fn coefficients(&self) -> (f64, f64, f64, f64) {
+        match self.velocity_update {
+            VelocityUpdate::Inertia {
+                inertia_weight,
+                cognitive,
+                social,
+            } => (1.0, inertia_weight, cognitive, social),
+            VelocityUpdate::Constriction { cognitive, social } => {
+                let phi = cognitive + social;
+                let chi = 2.0 / f64::abs(2.0 - phi - f64::sqrt(phi * phi - 4.0 * phi));
+                (chi, 1.0, cognitive, social)
+            }
+        }
+    }
+
+    pub fn step<R, F, E>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<Particle<F>, F>,
+        evaluator: &E,
+    ) -> usize
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy,
+        E: Evaluator<Particle<F>, F>,
+    {
+        let size = population.len();
+        let (chi, inertia_weight, cognitive, social) = self.coefficients();
+        let distribution = Uniform::new(0.0, 1.0);
+
+        let informants: Vec<Vec<f64>> = (0..size)
+            .map(|i| {
+                let particles = population.individuals();
+                self.topology
+                    .neighborhood(i, size)
+                    .into_iter()
+                    .map(|j| &particles[j])
+                    .max_by(|a, b| a.best_fitness.partial_cmp(&b.best_fitness).unwrap())
+                    .unwrap()
+                    .best_position
+                    .clone()
+            })
+            .collect();
+
+        for (particle, informant) in population.individuals_mut().iter_mut().zip(informants) {
+            let Particle {
+                position,
+                velocity,
+                best_position,
+                ..
+            } = particle;
+            for j in 0..velocity.len() {
+                let bounds = position.bounds[j];
+                let max_velocity = bounds.width();
+                let x = position.value[j];
+                let cognitive_pull = cognitive * rng.sample(distribution) * (best_position[j] - x);
+                let social_pull = social * rng.sample(distribution) * (informant[j] - x);
+                velocity[j] = (chi * (inertia_weight * velocity[j] + cognitive_pull + social_pull))
+                    .clamp(-max_velocity, max_velocity);
+
+                let moved = x + velocity[j];
+                position.value[j] = bounds.clamp(moved);
+                if position.value[j] != moved {
+                    velocity[j] = 0.0;
+                }
+            }
+        }
+
+        evaluator.evaluate(population.individuals_mut());
+        population.increment_ages();
+        population
+            .individuals_mut()
+            .iter_mut()
+            .for_each(Particle::update_best);
+
+        size
+    }
+
+    pub fn run<R, F, E, T, O>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<Particle<F>, F>,
+        evaluator: &E,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> RunState
+    where
+        R: Rng + ?Sized,
+        F: PartialOrd + Copy,
+        E: Evaluator<Particle<F>, F>,
+        T: Termination<Particle<F>, F>,
+        O: Observer<Particle<F>, F>,
+    {
+        let start = Instant::now();
+        let mut state = RunState::default();
+
+        population.evaluate(evaluator);
+        population
+            .individuals_mut()
+            .iter_mut()
+            .for_each(Particle::update_best);
+        state.evaluations += population.len();
+        state.elapsed = start.elapsed();
+        observer.on_initialization(population, &state);
+
+        while !termination.is_terminated(population, &state) && !observer.should_stop() {
+            state.evaluations += self.step(rng, population, evaluator);
+            state.generation += 1;
+            state.elapsed = start.elapsed();
+            observer.on_survivor_selection(population, &state);
+        }
+
+        observer.on_termination(population, &state);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::termination::MaxGenerations;
+
+    #[test]
+    fn test_topologies() {
+        let sphere = |particle: &Particle<f64>| {
+            -particle.position().value.iter().map(|x| x * x).sum::<f64>()
+        };
+
+        let swarms = [
+            ParticleSwarm::new(
+                Topology::Global,
+                VelocityUpdate::Inertia {
+                    inertia_weight: 0.7298,
+                    cognitive: 1.49618,
+                    social: 1.49618,
+                },
+            ),
+            ParticleSwarm::new(
+                Topology::Ring { neighbors: 1 },
+                VelocityUpdate::Constriction {
+                    cognitive: 2.05,
+                    social: 2.05,
+                },
+            ),
+            ParticleSwarm::new(
+                Topology::VonNeumann,
+                VelocityUpdate::Constriction {
+                    cognitive: 2.05,
+                    social: 2.05,
+                },
+            ),
+        ];
+
+        for swarm in swarms {
+            let mut rng = StdRng::seed_from_u64(1234);
+            let mut population = swarm.new_population(&mut rng, -5.0, 5.0, 5, 30);
+            swarm.run(
+                &mut rng,
+                &mut population,
+                &sphere,
+                &mut MaxGenerations::new(200),
+                &mut (),
+            );
+
+            let best_fitness = population
+                .individuals()
+                .iter()
+                .filter_map(|particle| particle.best_fitness())
+                .max_by(f64::total_cmp)
+                .unwrap();
+            assert!(best_fitness > -1e-3, "Best fitness: {}", best_fitness);
+        }
+    }
+}
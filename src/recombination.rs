@@ -3,16 +3,77 @@ use std::marker::PhantomData;
 use rand::Rng;
 use rand_distr::Uniform;
 
-use crate::parameter::{
-    BoundedValue, BoundedVector, GaussianStrategyParameter, SelfAdaptiveGaussianVector,
+use crate::{
+    boundary::{BoundaryHandler, Clamp},
+    parameter::{
+        BoundedValue, BoundedVector, GaussianStrategyParameter, SelfAdaptiveGaussianVector,
+    },
 };
 
+pub mod bit_string;
+pub mod differential;
 pub mod integer;
+pub mod mixed;
+pub mod multi_parent;
+pub mod permutation;
+
+/// Recombination of `N` parents into `K` children, where the number of children defaults to the
+/// number of parents.
+pub trait Recombinator<T, const N: usize, const K: usize = N> {
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&T; N]) -> [T; K];
+}
+
+/// Adapter keeping only the first child of the wrapped recombinator.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SingleChild<C> {
+    recombinator: C,
+}
+
+impl<C> SingleChild<C> {
+    pub fn new(recombinator: C) -> Self {
+        Self { recombinator }
+    }
+}
+
+impl<T, C, const N: usize> Recombinator<T, N, 1> for SingleChild<C>
+where
+    C: Recombinator<T, N>,
+{
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&T; N]) -> [T; 1] {
+        let children = self.recombinator.recombine(rng, parents);
+        [children
+            .into_iter()
+            .next()
+            .expect("The recombinator should produce at least one child")]
+    }
+}
 
-pub trait Recombinator<T, const N: usize> {
-    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&T; N]) -> [T; N];
+/// Adapter producing any number of children by recombining the same parents repeatedly into a
+/// single child.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Repeated<C> {
+    recombinator: C,
 }
 
+impl<C> Repeated<C> {
+    pub fn new(recombinator: C) -> Self {
+        Self { recombinator }
+    }
+}
+
+impl<T, C, const N: usize, const K: usize> Recombinator<T, N, K> for Repeated<C>
+where
+    C: Recombinator<T, N, 1>,
+{
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&T; N]) -> [T; K] {
+        std::array::from_fn(|_| {
+            let [child] = self.recombinator.recombine(rng, parents);
+            child
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Discrete;
 
 impl Recombinator<BoundedVector<f64>, 2> for Discrete {
@@ -42,6 +103,30 @@ impl Recombinator<BoundedVector<f64>, 2> for Discrete {
     }
 }
 
+impl<T> Recombinator<BoundedValue<T>, 2> for Discrete
+where
+    T: PartialOrd + Copy,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedValue<T>; 2],
+    ) -> [BoundedValue<T>; 2] {
+        let [parent_1, parent_2] = parents;
+
+        let mut child_1 = BoundedValue::clone(parent_1);
+        let mut child_2 = BoundedValue::clone(parent_2);
+
+        let distribution = Uniform::new_inclusive(0, 1);
+        let values = [parent_1.value, parent_2.value];
+        child_1.value = values[rng.sample(distribution)];
+        child_2.value = values[rng.sample(distribution)];
+
+        [child_1, child_2]
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SingleArithmetic {
     alpha: f64,
 }
@@ -87,6 +172,7 @@ impl Recombinator<BoundedVector<f64>, 2> for SingleArithmetic {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleArithmetic {
     alpha: f64,
     cross_point: usize,
@@ -124,6 +210,7 @@ impl Recombinator<BoundedVector<f64>, 2> for SimpleArithmetic {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WholeArithmetic {
     alpha: f64,
 }
@@ -165,17 +252,37 @@ impl Recombinator<BoundedValue<f64>, 2> for WholeArithmetic {
     }
 }
 
-pub struct BlendCrossover {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlendCrossover<B = Clamp> {
     alpha: f64,
+    boundary_handler: B,
 }
 
 impl BlendCrossover {
     pub fn new(alpha: f64) -> Self {
-        Self { alpha }
+        Self {
+            alpha,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> BlendCrossover<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> BlendCrossover<H> {
+        BlendCrossover {
+            alpha: self.alpha,
+            boundary_handler,
+        }
     }
 }
 
-impl Recombinator<BoundedVector<f64>, 2> for BlendCrossover {
+impl<B> Recombinator<BoundedVector<f64>, 2> for BlendCrossover<B>
+where
+    B: BoundaryHandler,
+{
     fn recombine<R: Rng + ?Sized>(
         &self,
         rng: &mut R,
@@ -189,30 +296,106 @@ impl Recombinator<BoundedVector<f64>, 2> for BlendCrossover {
             .value
             .iter_mut()
             .zip(child_2.value.iter_mut())
-            .for_each(|(ref_1, ref_2)| {
+            .zip(child_1.bounds.iter().zip(child_2.bounds.iter()))
+            .for_each(|((ref_1, ref_2), (bounds_1, bounds_2))| {
                 let value_1 = *ref_1;
                 let value_2 = *ref_2;
                 let distance = f64::abs(value_1 - value_2);
                 let min = f64::min(value_1, value_2) - self.alpha * distance;
                 let max = f64::max(value_1, value_2) + self.alpha * distance;
-                let distribution = Uniform::new(min, max);
-                *ref_1 = rng
-                    .sample(distribution)
-                    .clamp(child_1.min_value, child_1.max_value);
-                *ref_2 = rng
-                    .sample(distribution)
-                    .clamp(child_2.min_value, child_2.max_value);
+                // Inclusive, as identical parent values collapse the interval to a single point
+                let distribution = Uniform::new_inclusive(min, max);
+                let sample = |rng: &mut R| rng.sample(distribution);
+
+                let sampled = sample(rng);
+                *ref_1 = self
+                    .boundary_handler
+                    .repair(rng, sampled, value_1, bounds_1, sample);
+                let sampled = sample(rng);
+                *ref_2 = self
+                    .boundary_handler
+                    .repair(rng, sampled, value_2, bounds_2, sample);
+            });
+
+        [child_1, child_2]
+    }
+}
+
+/// Simulated Binary Crossover (SBX) with distribution index `eta`.
+///
+/// Every gene is crossed with probability 0.5, using the spread distribution truncated to the
+/// bounds of the gene as proposed by Deb and Agrawal. A larger `eta` keeps children closer to their
+/// parents.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulatedBinaryCrossover {
+    eta: f64,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(eta: f64) -> Self {
+        assert!(eta >= 0.0, "The distribution index should be non-negative");
+        Self { eta }
+    }
+
+    /// Spread factor for the child on the side of a bound at `distance` parent spans away.
+    fn spread_factor(&self, u: f64, distance: f64) -> f64 {
+        let beta = 1.0 + 2.0 * distance;
+        let alpha = 2.0 - beta.powf(-(self.eta + 1.0));
+
+        match u <= 1.0 / alpha {
+            true => (u * alpha).powf(1.0 / (self.eta + 1.0)),
+            false => (1.0 / (2.0 - u * alpha)).powf(1.0 / (self.eta + 1.0)),
+        }
+    }
+}
+
+impl Recombinator<BoundedVector<f64>, 2> for SimulatedBinaryCrossover {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>; 2],
+    ) -> [BoundedVector<f64>; 2] {
+        let [parent_1, parent_2] = parents;
+        let mut child_1 = BoundedVector::clone(parent_1);
+        let mut child_2 = BoundedVector::clone(parent_2);
+
+        child_1
+            .value
+            .iter_mut()
+            .zip(child_2.value.iter_mut())
+            .zip(parent_1.bounds.iter())
+            .for_each(|((ref_1, ref_2), bounds)| {
+                let (y_1, y_2) = (f64::min(*ref_1, *ref_2), f64::max(*ref_1, *ref_2));
+                if !rng.gen_bool(0.5) || y_2 - y_1 <= f64::EPSILON {
+                    return;
+                }
+
+                let min_value = bounds.min_value.unwrap_or(f64::NEG_INFINITY);
+                let max_value = bounds.max_value.unwrap_or(f64::INFINITY);
+                let span = y_2 - y_1;
+
+                let u = rng.gen_range(0.0..1.0);
+                let beta_1 = self.spread_factor(u, (y_1 - min_value) / span);
+                let beta_2 = self.spread_factor(u, (max_value - y_2) / span);
+                let c_1 = bounds.clamp(0.5 * (y_1 + y_2 - beta_1 * span));
+                let c_2 = bounds.clamp(0.5 * (y_1 + y_2 + beta_2 * span));
+
+                (*ref_1, *ref_2) = match rng.gen_bool(0.5) {
+                    true => (c_1, c_2),
+                    false => (c_2, c_1),
+                };
             });
 
         [child_1, child_2]
     }
 }
 
-pub struct SelfAdaptiveGaussianVectorRecombinator<TR, T, SR, S, const N: usize>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptiveGaussianVectorRecombinator<TR, T, SR, S, const N: usize, const K: usize = N>
 where
-    TR: Recombinator<BoundedVector<T>, N>,
+    TR: Recombinator<BoundedVector<T>, N, K>,
     T: PartialOrd,
-    SR: Recombinator<S, N>,
+    SR: Recombinator<S, N, K>,
     S: GaussianStrategyParameter,
 {
     value_recombinator: TR,
@@ -220,40 +403,123 @@ where
     _markers: PhantomData<(T, S)>,
 }
 
-impl<TR, SR> Recombinator<SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>, 2>
-    for SelfAdaptiveGaussianVectorRecombinator<TR, f64, SR, BoundedValue<f64>, 2>
+impl<TR, T, SR, S, const N: usize, const K: usize>
+    SelfAdaptiveGaussianVectorRecombinator<TR, T, SR, S, N, K>
+where
+    TR: Recombinator<BoundedVector<T>, N, K>,
+    T: PartialOrd,
+    SR: Recombinator<S, N, K>,
+    S: GaussianStrategyParameter,
+{
+    pub fn new(value_recombinator: TR, strategy_parameter_recombinator: SR) -> Self {
+        Self {
+            value_recombinator,
+            strategy_parameter_recombinator,
+            _markers: PhantomData,
+        }
+    }
+}
+
+impl<TR, SR, S, const N: usize, const K: usize>
+    Recombinator<SelfAdaptiveGaussianVector<f64, S>, N, K>
+    for SelfAdaptiveGaussianVectorRecombinator<TR, f64, SR, S, N, K>
 where
-    TR: Recombinator<BoundedVector<f64>, 2>,
-    SR: Recombinator<BoundedValue<f64>, 2>,
+    TR: Recombinator<BoundedVector<f64>, N, K>,
+    SR: Recombinator<S, N, K>,
+    S: GaussianStrategyParameter,
 {
     fn recombine<R: Rng + ?Sized>(
         &self,
         rng: &mut R,
-        parents: &[&SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>; 2],
-    ) -> [SelfAdaptiveGaussianVector<f64, BoundedValue<f64>>; 2] {
-        let [parent_1, parent_2] = parents;
-
-        let parent_values = [&parent_1.value, &parent_2.value];
-        let parent_strategy_parameters =
-            [&parent_1.strategy_parameter, &parent_2.strategy_parameter];
+        parents: &[&SelfAdaptiveGaussianVector<f64, S>; N],
+    ) -> [SelfAdaptiveGaussianVector<f64, S>; K] {
+        let parent_values = parents.map(|parent| &parent.value);
+        let parent_strategy_parameters = parents.map(|parent| &parent.strategy_parameter);
 
         let recombined_values = self.value_recombinator.recombine(rng, &parent_values);
         let recombined_strategy_parameters = self
             .strategy_parameter_recombinator
             .recombine(rng, &parent_strategy_parameters);
 
-        let children_vector: Vec<_> = recombined_values
-            .into_iter()
-            .zip(recombined_strategy_parameters)
-            .map(|(value, strategy_parameter)| SelfAdaptiveGaussianVector {
-                value,
-                strategy_parameter,
-            })
-            .collect();
-
-        match children_vector.try_into() {
-            Ok(children) => children,
-            _ => panic!("Fatal error. Unexpected amount of children created."),
+        let mut strategy_parameters = recombined_strategy_parameters.into_iter();
+        recombined_values.map(|value| SelfAdaptiveGaussianVector {
+            value,
+            strategy_parameter: strategy_parameters.next().unwrap(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::parameter::Bounds;
+
+    #[test]
+    fn test_simulated_binary_crossover() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bounds = vec![
+            Bounds::new(0.0, 1.0),
+            Bounds::new(-5.0, 5.0),
+            Bounds::unbounded(),
+        ];
+        let parent_1 = BoundedVector::new(bounds.clone(), vec![0.05, -4.0, 0.0]);
+        let parent_2 = BoundedVector::new(bounds.clone(), vec![0.95, 4.0, 10.0]);
+        let recombinator = SimulatedBinaryCrossover::new(15.0);
+
+        for _ in 0..1000 {
+            let children = recombinator.recombine(&mut rng, &[&parent_1, &parent_2]);
+            for child in children.iter() {
+                assert!(child
+                    .value
+                    .iter()
+                    .zip(&bounds)
+                    .all(|(value, bounds)| bounds.contains(value)));
+            }
+
+            // The mean of the children is preserved without clamping
+            let mean = (children[0].value[2] + children[1].value[2]) / 2.0;
+            assert!((mean - 5.0).abs() < 1e-9);
         }
     }
+
+    #[test]
+    fn test_child_count_adapters() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parent_1 = BoundedVector::with_shared_bounds(0.0, 1.0, vec![0.0; 3]);
+        let parent_2 = BoundedVector::with_shared_bounds(0.0, 1.0, vec![1.0; 3]);
+        let recombinator = Repeated::new(SingleChild::new(WholeArithmetic::new(0.25)));
+
+        let children: [BoundedVector<f64>; 5] =
+            recombinator.recombine(&mut rng, &[&parent_1, &parent_2]);
+        assert!(children
+            .iter()
+            .all(|child| child.value.iter().all(|&x| x == 0.25)));
+    }
+
+    #[test]
+    fn test_self_adaptive_n_step_sizes() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parents: [_; 3] = std::array::from_fn(|i| SelfAdaptiveGaussianVector {
+            value: BoundedVector::with_shared_bounds(-5.0, 5.0, vec![i as f64; 4]),
+            strategy_parameter: BoundedVector::with_shared_bounds(
+                0.0,
+                1.0,
+                vec![0.25 * i as f64; 4],
+            ),
+        });
+        let recombinator = SelfAdaptiveGaussianVectorRecombinator::new(
+            multi_parent::GlobalDiscrete,
+            multi_parent::GlobalIntermediate,
+        );
+
+        let [child] = recombinator.recombine(&mut rng, &parents.each_ref());
+        assert!(child
+            .value
+            .value
+            .iter()
+            .all(|x| [0.0, 1.0, 2.0].contains(x)));
+        assert!(child.strategy_parameter.value.iter().all(|&x| x == 0.25));
+    }
 }
@@ -0,0 +1,103 @@
+use rand::Rng;
+
+use super::{
+    integer::{
+        crossover_masked, n_point_mask, one_point_mask, uniform_mask, NPoint, OnePoint, Uniform,
+    },
+    Recombinator,
+};
+use crate::parameter::BitString;
+
+impl Recombinator<BitString, 2> for OnePoint {
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&BitString; 2]) -> [BitString; 2] {
+        let mut children = parents.map(BitString::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = one_point_mask(rng, child_1.len());
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
+impl Recombinator<BitString, 2> for NPoint {
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&BitString; 2]) -> [BitString; 2] {
+        let mut children = parents.map(BitString::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = n_point_mask(rng, child_1.len(), self.points);
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
+impl Recombinator<BitString, 2> for Uniform {
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&BitString; 2]) -> [BitString; 2] {
+        let mut children = parents.map(BitString::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = uniform_mask(rng, child_1.len());
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        mutation::{bit_string::BitFlip, Mutator},
+        parameter::{BinaryEncoding, BitStringDecoder, Bounds},
+    };
+
+    #[test]
+    fn test_one_max() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mutator = BitFlip::new(1.0 / 1000.0);
+
+        let mut population: Vec<_> = (0..20).map(|_| BitString::random(&mut rng, 1000)).collect();
+        let initial_best = population.iter().map(BitString::count_ones).max().unwrap();
+
+        for _ in 0..50 {
+            population.sort_by_key(|x| std::cmp::Reverse(x.count_ones()));
+            let mut offspring: Vec<_> = population[..10]
+                .chunks_exact(2)
+                .flat_map(|parents| Uniform.recombine(&mut rng, &[&parents[0], &parents[1]]))
+                .collect();
+            offspring.iter_mut().for_each(|child| {
+                mutator.mutate(&mut rng, child);
+            });
+            population.truncate(20 - offspring.len());
+            population.extend(offspring);
+        }
+
+        let best = population.iter().map(BitString::count_ones).max().unwrap();
+        assert!(best > initial_best);
+    }
+
+    #[test]
+    fn test_decoder() {
+        let bits = BitString::new(vec![true, true, false, false, true, false]);
+
+        let binary = BitStringDecoder::new(BinaryEncoding::Binary, 3);
+        assert_eq!(binary.decode_unsigned(&bits), vec![6, 2]);
+
+        let gray = BitStringDecoder::new(BinaryEncoding::Gray, 3);
+        assert_eq!(gray.decode_unsigned(&bits), vec![4, 3]);
+        assert_eq!(
+            gray.decode_reals(&bits, &[Bounds::new(0.0, 7.0), Bounds::new(-1.0, 1.0)]),
+            vec![4.0, -1.0 + 2.0 * 3.0 / 7.0]
+        );
+        assert_eq!(
+            binary.decode_integers(&bits, &[Bounds::new(-7, 7), Bounds::new(0, 7)]),
+            vec![5, 2]
+        );
+
+        // The range of the bounds exceeds i64
+        let bits = BitString::new([vec![true; 64], vec![false; 64]].concat());
+        let full_range = Bounds::new(i64::MIN, i64::MAX);
+        assert_eq!(
+            BitStringDecoder::new(BinaryEncoding::Binary, 64)
+                .decode_integers(&bits, &[full_range; 2]),
+            vec![i64::MAX, i64::MIN]
+        );
+    }
+}
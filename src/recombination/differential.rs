@@ -0,0 +1,242 @@
+use rand::Rng;
+use rand_distr::Uniform;
+
+use super::Recombinator;
+use crate::{
+    boundary::{BoundaryHandler, MidpointToParent},
+    parameter::BoundedVector,
+};
+
+/// Construction of the mutant vector from the target and the donors.
+///
+/// Parents are passed as the target followed by the donors `x_1, x_2, ...`, where strategies
+/// building on a best vector take it as the first donor.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifferentialMutation {
+    /// `x_1 + F (x_2 - x_3)`
+    Rand1,
+    /// `x_best + F (x_2 - x_3)`, with `x_best` as first donor.
+    Best1,
+    /// `x_i + F (x_best - x_i) + F (x_2 - x_3)`, with `x_best` as first donor.
+    CurrentToBest1,
+    /// `x_1 + F (x_2 - x_3) + F (x_4 - x_5)`
+    Rand2,
+    /// `x_i + F (x_pbest - x_i) + F (x_2 - x_3)`, with a random one of the best `p` fraction of the
+    /// population as first donor.
+    ///
+    /// As operator it equals [`DifferentialMutation::CurrentToBest1`], choosing the donors is left
+    /// to the caller.
+    CurrentToPBest1 { p: f64 },
+}
+
+impl DifferentialMutation {
+    /// Number of parents including the target.
+    pub fn number_parents(&self) -> usize {
+        match self {
+            Self::Rand1 | Self::Best1 | Self::CurrentToBest1 | Self::CurrentToPBest1 { .. } => 4,
+            Self::Rand2 => 6,
+        }
+    }
+}
+
+/// Mixing of the mutant vector with the target vector.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifferentialCrossover {
+    Binomial,
+    Exponential,
+}
+
+/// Differential Evolution trial vector generation, recombining a target and its donors into a
+/// single trial vector.
+///
+/// Components leaving the bounds are repaired with the target value as parent, by default placing
+/// them halfway between the target value and the violated bound.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DifferentialRecombinator<B = MidpointToParent> {
+    mutation: DifferentialMutation,
+    crossover: DifferentialCrossover,
+    scale_factor: f64,
+    crossover_rate: f64,
+    boundary_handler: B,
+}
+
+impl DifferentialRecombinator {
+    pub fn new(
+        mutation: DifferentialMutation,
+        crossover: DifferentialCrossover,
+        scale_factor: f64,
+        crossover_rate: f64,
+    ) -> Self {
+        Self {
+            mutation,
+            crossover,
+            scale_factor,
+            crossover_rate: crossover_rate.clamp(0.0, 1.0),
+            boundary_handler: MidpointToParent,
+        }
+    }
+}
+
+impl<B> DifferentialRecombinator<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> DifferentialRecombinator<H> {
+        DifferentialRecombinator {
+            mutation: self.mutation,
+            crossover: self.crossover,
+            scale_factor: self.scale_factor,
+            crossover_rate: self.crossover_rate,
+            boundary_handler,
+        }
+    }
+
+    fn mutant(&self, parents: &[&BoundedVector<f64>]) -> Vec<f64> {
+        let vector = |i: usize| &parents[i].value;
+        let difference = |a: usize, b: usize, j: usize| {
+            self.scale_factor * (parents[a].value[j] - parents[b].value[j])
+        };
+        let current = vector(0);
+
+        (0..current.len())
+            .map(|j| match self.mutation {
+                DifferentialMutation::Rand1 | DifferentialMutation::Best1 => {
+                    vector(1)[j] + difference(2, 3, j)
+                }
+                DifferentialMutation::CurrentToBest1
+                | DifferentialMutation::CurrentToPBest1 { .. } => {
+                    current[j] + difference(1, 0, j) + difference(2, 3, j)
+                }
+                DifferentialMutation::Rand2 => {
+                    vector(1)[j] + difference(2, 3, j) + difference(4, 5, j)
+                }
+            })
+            .collect()
+    }
+
+    fn crossover<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        target: &BoundedVector<f64>,
+        mutant: Vec<f64>,
+    ) -> BoundedVector<f64> {
+        let length = target.value.len();
+        let mut trial = target.clone();
+        if length == 0 {
+            return trial;
+        }
+
+        let start = rng.gen_range(0..length);
+        let distribution = Uniform::new(0.0, 1.0);
+
+        match self.crossover {
+            DifferentialCrossover::Binomial => {
+                for (j, value) in mutant.into_iter().enumerate() {
+                    if j == start || rng.sample(distribution) < self.crossover_rate {
+                        trial.value[j] = value;
+                    }
+                }
+            }
+            DifferentialCrossover::Exponential => {
+                let mut offset = 0;
+                loop {
+                    let j = (start + offset) % length;
+                    trial.value[j] = mutant[j];
+                    offset += 1;
+                    if offset >= length || rng.sample(distribution) >= self.crossover_rate {
+                        break;
+                    }
+                }
+            }
+        }
+
+        trial
+    }
+
+    /// Trial vector of the target `parents[0]` and the donors `parents[1..]`.
+    pub(crate) fn trial<R>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>],
+    ) -> BoundedVector<f64>
+    where
+        R: Rng + ?Sized,
+        B: BoundaryHandler,
+    {
+        assert_eq!(
+            parents.len(),
+            self.mutation.number_parents(),
+            "The mutation strategy requires the target and {} donors",
+            self.mutation.number_parents() - 1
+        );
+
+        let target = parents[0];
+        let mut trial = self.crossover(rng, target, self.mutant(parents));
+        trial
+            .value
+            .iter_mut()
+            .zip(&target.value)
+            .zip(&target.bounds)
+            .for_each(|((value, &parent), bounds)| {
+                let mutant = *value;
+                *value = self
+                    .boundary_handler
+                    .repair(rng, mutant, parent, bounds, |_| mutant);
+            });
+
+        trial
+    }
+}
+
+impl<B, const N: usize> Recombinator<BoundedVector<f64>, N, 1> for DifferentialRecombinator<B>
+where
+    B: BoundaryHandler,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>; N],
+    ) -> [BoundedVector<f64>; 1] {
+        [self.trial(rng, parents)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_differential_recombinator() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let target = BoundedVector::with_shared_bounds(-10.0, 10.0, vec![0.0; 5]);
+        let donor_1 = BoundedVector::with_shared_bounds(-10.0, 10.0, vec![1.0; 5]);
+        let donor_2 = BoundedVector::with_shared_bounds(-10.0, 10.0, vec![3.0; 5]);
+        let donor_3 = BoundedVector::with_shared_bounds(-10.0, 10.0, vec![2.0; 5]);
+        let parents = [&target, &donor_1, &donor_2, &donor_3];
+
+        // Crossover rate 1 takes the full mutant x_1 + F (x_2 - x_3)
+        let recombinator = DifferentialRecombinator::new(
+            DifferentialMutation::Rand1,
+            DifferentialCrossover::Binomial,
+            0.5,
+            1.0,
+        );
+        let [trial] = recombinator.recombine(&mut rng, &parents);
+        assert_eq!(trial.value, vec![1.5; 5]);
+
+        // Crossover rate 0 takes exactly one component of the mutant
+        let recombinator = DifferentialRecombinator::new(
+            DifferentialMutation::CurrentToBest1,
+            DifferentialCrossover::Exponential,
+            0.5,
+            0.0,
+        );
+        let [trial] = recombinator.recombine(&mut rng, &parents);
+        assert_eq!(trial.value.iter().filter(|&&x| x == 1.0).count(), 1);
+        assert_eq!(trial.value.iter().filter(|&&x| x == 0.0).count(), 4);
+    }
+}
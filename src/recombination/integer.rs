@@ -4,11 +4,14 @@ use rand::{seq::IteratorRandom, Rng};
 use rand_distr::Bernoulli;
 
 use super::Recombinator;
+use crate::parameter::BoundedVector;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnePoint;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NPoint {
-    points: NonZeroUsize,
+    pub(super) points: NonZeroUsize,
 }
 
 impl NPoint {
@@ -17,8 +20,108 @@ impl NPoint {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uniform;
 
+/// Exchanges the genes of both values wherever `mask` is unset.
+pub(super) fn crossover_masked<T>(
+    value_1: &mut [T],
+    value_2: &mut [T],
+    mask: impl Iterator<Item = bool>,
+) {
+    assert_eq!(
+        value_1.len(),
+        value_2.len(),
+        "The parents should have an equal amount of genes"
+    );
+
+    value_1
+        .iter_mut()
+        .zip(value_2.iter_mut())
+        .zip(mask)
+        .filter(|(_, keep)| !keep)
+        .for_each(|((a, b), _)| std::mem::swap(a, b));
+}
+
+pub(super) fn one_point_mask<R: Rng + ?Sized>(rng: &mut R, length: usize) -> Vec<bool> {
+    let point = match length {
+        0 | 1 => length,
+        _ => rng.gen_range(1..length),
+    };
+    (0..length).map(|i| i < point).collect()
+}
+
+pub(super) fn n_point_mask<R: Rng + ?Sized>(
+    rng: &mut R,
+    length: usize,
+    points: NonZeroUsize,
+) -> Vec<bool> {
+    let mut points = (1..length).choose_multiple(rng, points.get());
+    points.sort_unstable();
+
+    // Segments alternate between the parents at every crossover point
+    (0..length)
+        .map(|i| points.iter().filter(|&&point| point <= i).count() % 2 == 0)
+        .collect()
+}
+
+pub(super) fn uniform_mask<R: Rng + ?Sized>(rng: &mut R, length: usize) -> Vec<bool> {
+    rng.sample_iter(Bernoulli::new(0.5).unwrap())
+        .take(length)
+        .collect()
+}
+
+impl<T> Recombinator<BoundedVector<T>, 2> for OnePoint
+where
+    T: PartialOrd + Clone,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<T>; 2],
+    ) -> [BoundedVector<T>; 2] {
+        let mut children = parents.map(BoundedVector::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = one_point_mask(rng, child_1.value.len());
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
+impl<T> Recombinator<BoundedVector<T>, 2> for NPoint
+where
+    T: PartialOrd + Clone,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<T>; 2],
+    ) -> [BoundedVector<T>; 2] {
+        let mut children = parents.map(BoundedVector::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = n_point_mask(rng, child_1.value.len(), self.points);
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
+impl<T> Recombinator<BoundedVector<T>, 2> for Uniform
+where
+    T: PartialOrd + Clone,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<T>; 2],
+    ) -> [BoundedVector<T>; 2] {
+        let mut children = parents.map(BoundedVector::clone);
+        let [child_1, child_2] = &mut children;
+        let mask = uniform_mask(rng, child_1.value.len());
+        crossover_masked(&mut child_1.value, &mut child_2.value, mask.into_iter());
+        children
+    }
+}
+
 macro_rules! int_recombination_impl {
     ($($t:ty)*) => {
         $(
@@ -82,3 +185,34 @@ macro_rules! int_recombination_impl {
 }
 
 int_recombination_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_genewise_crossover() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parent_1 = BoundedVector::with_shared_bounds(0, 1, vec![0; 10]);
+        let parent_2 = BoundedVector::with_shared_bounds(0, 1, vec![1; 10]);
+        let parents = [&parent_1, &parent_2];
+
+        let [child_1, _] = OnePoint.recombine(&mut rng, &parents);
+        assert_eq!(child_1.value[0], 0);
+        assert_eq!(child_1.value[9], 1);
+        assert!(child_1.value.windows(2).all(|x| x[0] <= x[1]));
+
+        let [child_1, _] = NPoint::new(NonZeroUsize::new(3).unwrap()).recombine(&mut rng, &parents);
+        let switches = child_1.value.windows(2).filter(|x| x[0] != x[1]).count();
+        assert_eq!(switches, 3);
+
+        let [child_1, child_2] = Uniform.recombine(&mut rng, &parents);
+        assert!(child_1
+            .value
+            .iter()
+            .zip(&child_2.value)
+            .all(|(a, b)| a + b == 1));
+    }
+}
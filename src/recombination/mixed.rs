@@ -0,0 +1,176 @@
+use rand::Rng;
+use rand_distr::Uniform;
+
+use super::{Discrete, Recombinator};
+use crate::parameter::{BoundedValue, Categorical, MixedGene, MixedVector, Ordinal};
+
+impl Recombinator<Ordinal, 2> for Discrete {
+    fn recombine<R: Rng + ?Sized>(&self, rng: &mut R, parents: &[&Ordinal; 2]) -> [Ordinal; 2] {
+        let distribution = Uniform::new_inclusive(0, 1);
+        [
+            *parents[rng.sample(distribution)],
+            *parents[rng.sample(distribution)],
+        ]
+    }
+}
+
+impl Recombinator<Categorical, 2> for Discrete {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&Categorical; 2],
+    ) -> [Categorical; 2] {
+        let distribution = Uniform::new_inclusive(0, 1);
+        [
+            *parents[rng.sample(distribution)],
+            *parents[rng.sample(distribution)],
+        ]
+    }
+}
+
+/// Recombines every pair of genes of two `MixedVector` parents with the recombinator belonging to
+/// their kind.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixedVectorRecombinator<RR, IR, OR, CR> {
+    real_recombinator: RR,
+    integer_recombinator: IR,
+    ordinal_recombinator: OR,
+    categorical_recombinator: CR,
+}
+
+impl<RR, IR, OR, CR> MixedVectorRecombinator<RR, IR, OR, CR>
+where
+    RR: Recombinator<BoundedValue<f64>, 2>,
+    IR: Recombinator<BoundedValue<i64>, 2>,
+    OR: Recombinator<Ordinal, 2>,
+    CR: Recombinator<Categorical, 2>,
+{
+    pub fn new(
+        real_recombinator: RR,
+        integer_recombinator: IR,
+        ordinal_recombinator: OR,
+        categorical_recombinator: CR,
+    ) -> Self {
+        Self {
+            real_recombinator,
+            integer_recombinator,
+            ordinal_recombinator,
+            categorical_recombinator,
+        }
+    }
+}
+
+impl<RR, IR, OR, CR> Recombinator<MixedVector, 2> for MixedVectorRecombinator<RR, IR, OR, CR>
+where
+    RR: Recombinator<BoundedValue<f64>, 2>,
+    IR: Recombinator<BoundedValue<i64>, 2>,
+    OR: Recombinator<Ordinal, 2>,
+    CR: Recombinator<Categorical, 2>,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&MixedVector; 2],
+    ) -> [MixedVector; 2] {
+        let [parent_1, parent_2] = parents;
+        assert_eq!(
+            parent_1.genes.len(),
+            parent_2.genes.len(),
+            "The parents should have an equal amount of genes"
+        );
+
+        let (genes_1, genes_2) = parent_1
+            .genes
+            .iter()
+            .zip(parent_2.genes.iter())
+            .map(|pair| {
+                let [gene_1, gene_2] = match pair {
+                    (MixedGene::Real(a), MixedGene::Real(b)) => self
+                        .real_recombinator
+                        .recombine(rng, &[a, b])
+                        .map(MixedGene::Real),
+                    (MixedGene::Integer(a), MixedGene::Integer(b)) => self
+                        .integer_recombinator
+                        .recombine(rng, &[a, b])
+                        .map(MixedGene::Integer),
+                    (MixedGene::Ordinal(a), MixedGene::Ordinal(b)) => self
+                        .ordinal_recombinator
+                        .recombine(rng, &[a, b])
+                        .map(MixedGene::Ordinal),
+                    (MixedGene::Categorical(a), MixedGene::Categorical(b)) => self
+                        .categorical_recombinator
+                        .recombine(rng, &[a, b])
+                        .map(MixedGene::Categorical),
+                    _ => panic!("Genes at the same position should be of the same kind"),
+                };
+                (gene_1, gene_2)
+            })
+            .unzip();
+
+        [MixedVector::new(genes_1), MixedVector::new(genes_2)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        mutation::{
+            mixed::{CategoricalResetting, MixedVectorMutator, OrdinalCreep},
+            Mutator, SimpleGaussian, UniformMutator,
+        },
+        recombination::WholeArithmetic,
+    };
+
+    fn hyperparameters(learning_rate: f64, layers: i64, activation: usize) -> MixedVector {
+        MixedVector::new(vec![
+            MixedGene::Real(BoundedValue {
+                min_value: 1e-5,
+                max_value: 1.0,
+                value: learning_rate,
+            }),
+            MixedGene::Integer(BoundedValue {
+                min_value: 1,
+                max_value: 8,
+                value: layers,
+            }),
+            MixedGene::Ordinal(Ordinal::new(0, 4)),
+            MixedGene::Categorical(Categorical::new(activation, 3)),
+        ])
+    }
+
+    #[test]
+    fn test_mixed_vector_operators() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let recombinator =
+            MixedVectorRecombinator::new(WholeArithmetic::new(0.5), Discrete, Discrete, Discrete);
+        let mutator = MixedVectorMutator::new(
+            SimpleGaussian::new(0.1),
+            UniformMutator::new(1.0),
+            OrdinalCreep::new(1.0),
+            CategoricalResetting::new(1.0),
+        );
+
+        let parent_1 = hyperparameters(0.1, 2, 0);
+        let parent_2 = hyperparameters(0.3, 6, 0);
+        let [mut child, _] = recombinator.recombine(&mut rng, &[&parent_1, &parent_2]);
+        match &child.genes[0] {
+            MixedGene::Real(gene) => assert!((gene.value - 0.2).abs() < 1e-12),
+            _ => panic!("Gene kinds should be preserved"),
+        }
+
+        mutator.mutate(&mut rng, &mut child);
+        match child.genes.as_slice() {
+            [MixedGene::Real(real), MixedGene::Integer(integer), MixedGene::Ordinal(ordinal), MixedGene::Categorical(categorical)] =>
+            {
+                assert!((real.min_value..=real.max_value).contains(&real.value));
+                assert!((1..=8).contains(&integer.value));
+                assert_eq!(ordinal.value, 1);
+                assert_ne!(categorical.value, 0);
+            }
+            _ => panic!("Gene kinds should be preserved"),
+        }
+    }
+}
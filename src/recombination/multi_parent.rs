@@ -0,0 +1,546 @@
+use std::array;
+
+use nalgebra::DVector;
+use rand::Rng;
+use rand_distr::{Normal, StandardNormal, Uniform};
+
+use super::Recombinator;
+use crate::{
+    boundary::{BoundaryHandler, Clamp},
+    parameter::{BoundedValue, BoundedVector, CorrelatedStrategyParameter},
+};
+
+/// Every child is the centroid of all parents, as used by (mu/rho_I, lambda)-ES.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalIntermediate;
+
+/// Every gene of every child is taken from a uniformly chosen parent, as used by
+/// (mu/rho_D, lambda)-ES.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalDiscrete;
+
+/// Unimodal Normal Distribution Crossover (UNDX-m), requiring at least three parents.
+///
+/// The last parent determines the spread orthogonal to the subspace spanned by the others.
+/// Violating genes are repaired with the centroid of the other parents as parent value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnimodalNormalDistribution<B = Clamp> {
+    sigma_zeta: Option<f64>,
+    sigma_eta: Option<f64>,
+    boundary_handler: B,
+}
+
+/// Simplex crossover (SPX), sampling uniformly within the simplex of the parents expanded by
+/// `epsilon` around its centroid.
+///
+/// Violating genes are repaired with the centroid of the parents as parent value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Simplex<B = Clamp> {
+    epsilon: f64,
+    boundary_handler: B,
+}
+
+/// Parent-centric crossover (PCX), where the k-th child is centered around the k-th parent, cycling
+/// through the parents when more children than parents are requested.
+///
+/// Violating genes are repaired with the parent the child is centered around as parent value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParentCentric<B = Clamp> {
+    sigma_zeta: f64,
+    sigma_eta: f64,
+    boundary_handler: B,
+}
+
+impl UnimodalNormalDistribution {
+    /// Uses the recommended deviations, depending on the number of parents and the dimension.
+    pub fn new() -> Self {
+        Self {
+            sigma_zeta: None,
+            sigma_eta: None,
+            boundary_handler: Clamp,
+        }
+    }
+
+    pub fn with_deviations(sigma_zeta: f64, sigma_eta: f64) -> Self {
+        Self {
+            sigma_zeta: Some(sigma_zeta),
+            sigma_eta: Some(sigma_eta),
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> UnimodalNormalDistribution<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> UnimodalNormalDistribution<H> {
+        UnimodalNormalDistribution {
+            sigma_zeta: self.sigma_zeta,
+            sigma_eta: self.sigma_eta,
+            boundary_handler,
+        }
+    }
+}
+
+impl Default for UnimodalNormalDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simplex {
+    pub fn new(epsilon: f64) -> Self {
+        Self {
+            epsilon,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> Simplex<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(self, boundary_handler: H) -> Simplex<H> {
+        Simplex {
+            epsilon: self.epsilon,
+            boundary_handler,
+        }
+    }
+}
+
+impl ParentCentric {
+    pub fn new(sigma_zeta: f64, sigma_eta: f64) -> Self {
+        Self {
+            sigma_zeta,
+            sigma_eta,
+            boundary_handler: Clamp,
+        }
+    }
+}
+
+impl<B> ParentCentric<B> {
+    pub fn with_boundary_handler<H: BoundaryHandler>(
+        self,
+        boundary_handler: H,
+    ) -> ParentCentric<H> {
+        ParentCentric {
+            sigma_zeta: self.sigma_zeta,
+            sigma_eta: self.sigma_eta,
+            boundary_handler,
+        }
+    }
+}
+
+impl Default for ParentCentric {
+    fn default() -> Self {
+        Self::new(0.1, 0.1)
+    }
+}
+
+fn to_vectors<const N: usize>(parents: &[&BoundedVector<f64>; N]) -> [DVector<f64>; N] {
+    let length = parents[0].value.len();
+    assert!(
+        parents.iter().all(|parent| parent.value.len() == length),
+        "The parents should have an equal amount of genes"
+    );
+
+    parents.map(|parent| DVector::from_column_slice(&parent.value))
+}
+
+fn centroid(vectors: &[DVector<f64>]) -> DVector<f64> {
+    vectors.iter().sum::<DVector<f64>>() / vectors.len() as f64
+}
+
+/// Child with the bounds of the first parent, clamped to those bounds.
+fn to_child(parents: &[&BoundedVector<f64>], value: DVector<f64>) -> BoundedVector<f64> {
+    let mut child = BoundedVector::new(parents[0].bounds.clone(), value.data.into());
+    child.clamp();
+    child
+}
+
+/// Child with the bounds of the first parent, sampled by `sample` and repaired gene by gene with
+/// `parent` as parent value.
+///
+/// Resampling a violating gene draws it from its marginal of the sampled distribution.
+fn to_repaired_child<R, B, S>(
+    rng: &mut R,
+    boundary_handler: &B,
+    parents: &[&BoundedVector<f64>],
+    parent: &DVector<f64>,
+    mut sample: S,
+) -> BoundedVector<f64>
+where
+    R: Rng + ?Sized,
+    B: BoundaryHandler,
+    S: FnMut(&mut R) -> DVector<f64>,
+{
+    let bounds = &parents[0].bounds;
+    let sampled = sample(rng);
+    let value = (0..sampled.len())
+        .map(|i| {
+            boundary_handler.repair(rng, sampled[i], parent[i], &bounds[i], |rng: &mut R| {
+                sample(rng)[i]
+            })
+        })
+        .collect();
+    BoundedVector::new(bounds.clone(), value)
+}
+
+/// Standard normal sample with the components within the span of `basis` removed.
+fn sample_orthogonal<R: Rng + ?Sized>(
+    rng: &mut R,
+    basis: &[DVector<f64>],
+    length: usize,
+) -> DVector<f64> {
+    let mut sample = DVector::from_fn(length, |_, _| rng.sample(StandardNormal));
+    for direction in basis {
+        sample -= direction * direction.dot(&sample);
+    }
+    sample
+}
+
+/// Orthonormal basis of the span of the vectors by Gram-Schmidt, skipping dependent vectors.
+fn orthonormal_basis<'a>(vectors: impl Iterator<Item = &'a DVector<f64>>) -> Vec<DVector<f64>> {
+    let mut basis: Vec<DVector<f64>> = Vec::new();
+    for vector in vectors {
+        let mut direction = vector.clone();
+        for e in basis.iter() {
+            direction -= e * e.dot(vector);
+        }
+
+        let norm = direction.norm();
+        if norm > f64::EPSILON * vector.norm().max(1.0) {
+            basis.push(direction / norm);
+        }
+    }
+    basis
+}
+
+impl<const N: usize, const K: usize> Recombinator<BoundedVector<f64>, N, K> for GlobalIntermediate {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        _rng: &mut R,
+        parents: &[&BoundedVector<f64>; N],
+    ) -> [BoundedVector<f64>; K] {
+        let center = centroid(&to_vectors(parents));
+        array::from_fn(|_| to_child(parents, center.clone()))
+    }
+}
+
+impl<const N: usize, const K: usize> Recombinator<BoundedValue<f64>, N, K> for GlobalIntermediate {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        _rng: &mut R,
+        parents: &[&BoundedValue<f64>; N],
+    ) -> [BoundedValue<f64>; K] {
+        let mean = parents.iter().map(|parent| parent.value).sum::<f64>() / N as f64;
+        array::from_fn(|_| BoundedValue {
+            value: mean.clamp(parents[0].min_value, parents[0].max_value),
+            ..BoundedValue::clone(parents[0])
+        })
+    }
+}
+
+impl<const N: usize, const K: usize> Recombinator<CorrelatedStrategyParameter, N, K>
+    for GlobalIntermediate
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&CorrelatedStrategyParameter; N],
+    ) -> [CorrelatedStrategyParameter; K] {
+        let [step_sizes]: [BoundedVector<f64>; 1] =
+            self.recombine(rng, &parents.map(|parent| &parent.step_sizes));
+        // Circular mean, as angles near -pi and pi describe nearly the same rotation
+        let rotation_angles = (0..parents[0].rotation_angles.len())
+            .map(|i| {
+                let (sin, cos) = parents
+                    .iter()
+                    .map(|parent| parent.rotation_angles[i].sin_cos())
+                    .fold((0.0, 0.0), |(sin, cos), (s, c)| (sin + s, cos + c));
+                f64::atan2(sin, cos)
+            })
+            .collect();
+
+        let child = CorrelatedStrategyParameter::new(step_sizes, rotation_angles);
+        array::from_fn(|_| child.clone())
+    }
+}
+
+impl<T, const N: usize, const K: usize> Recombinator<BoundedVector<T>, N, K> for GlobalDiscrete
+where
+    T: PartialOrd + Clone,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<T>; N],
+    ) -> [BoundedVector<T>; K] {
+        let distribution = Uniform::new(0, N);
+        array::from_fn(|_| {
+            let mut child = BoundedVector::clone(parents[0]);
+            child
+                .value
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, value)| *value = parents[rng.sample(distribution)].value[i].clone());
+            child
+        })
+    }
+}
+
+impl<B, const N: usize, const K: usize> Recombinator<BoundedVector<f64>, N, K>
+    for UnimodalNormalDistribution<B>
+where
+    B: BoundaryHandler,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>; N],
+    ) -> [BoundedVector<f64>; K] {
+        assert!(N >= 3, "UNDX requires at least three parents");
+
+        let vectors = to_vectors(parents);
+        let length = vectors[0].len();
+        let (primary, [secondary]) = vectors.split_at(N - 1) else {
+            unreachable!()
+        };
+
+        let center = centroid(primary);
+        let differences: Vec<_> = primary.iter().map(|x| x - &center).collect();
+        let basis = orthonormal_basis(differences.iter());
+
+        // Distance of the secondary parent to the primary search subspace
+        let difference = secondary - &center;
+        let distance = basis
+            .iter()
+            .fold(difference.clone(), |acc, e| acc - e * e.dot(&difference))
+            .norm();
+
+        let sigma_zeta = self
+            .sigma_zeta
+            .unwrap_or(1.0 / f64::sqrt((N - 2).max(1) as f64));
+        let sigma_eta = self
+            .sigma_eta
+            .unwrap_or(0.35 / f64::sqrt(length.saturating_sub(N - 2).max(1) as f64));
+        let zeta = Normal::new(0.0, sigma_zeta).unwrap();
+
+        let sample = |rng: &mut R| {
+            let primary_component = differences
+                .iter()
+                .fold(DVector::zeros(length), |acc, d| acc + d * rng.sample(zeta));
+            let secondary_component =
+                sample_orthogonal(rng, &basis, length) * (distance * sigma_eta);
+
+            &center + primary_component + secondary_component
+        };
+
+        array::from_fn(|_| to_repaired_child(rng, &self.boundary_handler, parents, &center, sample))
+    }
+}
+
+impl<B, const N: usize, const K: usize> Recombinator<BoundedVector<f64>, N, K> for Simplex<B>
+where
+    B: BoundaryHandler,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>; N],
+    ) -> [BoundedVector<f64>; K] {
+        assert!(N >= 2, "SPX requires at least two parents");
+
+        let vectors = to_vectors(parents);
+        let center = centroid(&vectors);
+        let expanded: Vec<_> = vectors
+            .iter()
+            .map(|x| &center + (x - &center) * self.epsilon)
+            .collect();
+
+        let sample = |rng: &mut R| {
+            let mut offset = DVector::zeros(center.len());
+            for k in 1..N {
+                let r = rng.gen_range(0.0f64..1.0).powf(1.0 / k as f64);
+                offset = (&expanded[k - 1] - &expanded[k] + offset) * r;
+            }
+
+            &expanded[N - 1] + offset
+        };
+
+        array::from_fn(|_| to_repaired_child(rng, &self.boundary_handler, parents, &center, sample))
+    }
+}
+
+impl<B, const N: usize, const K: usize> Recombinator<BoundedVector<f64>, N, K> for ParentCentric<B>
+where
+    B: BoundaryHandler,
+{
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&BoundedVector<f64>; N],
+    ) -> [BoundedVector<f64>; K] {
+        assert!(N >= 2, "PCX requires at least two parents");
+
+        let vectors = to_vectors(parents);
+        let length = vectors[0].len();
+        let center = centroid(&vectors);
+        let zeta = Normal::new(0.0, self.sigma_zeta).unwrap();
+
+        let mut index = 0;
+        array::from_fn(|_| {
+            let parent = &vectors[index % N];
+            let direction = parent - &center;
+            let basis = orthonormal_basis([&direction].into_iter());
+
+            // Mean perpendicular distance of the other parents to the parent direction
+            let mean_distance = vectors
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != index % N)
+                .map(|(_, x)| {
+                    let offset = x - parent;
+                    basis
+                        .iter()
+                        .fold(offset.clone(), |acc, e| acc - e * e.dot(&offset))
+                        .norm()
+                })
+                .sum::<f64>()
+                / (N - 1) as f64;
+
+            let sample = |rng: &mut R| {
+                parent
+                    + &direction * rng.sample(zeta)
+                    + sample_orthogonal(rng, &basis, length) * (mean_distance * self.sigma_eta)
+            };
+
+            index += 1;
+            to_repaired_child(rng, &self.boundary_handler, parents, parent, sample)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn parents(rng: &mut StdRng) -> [BoundedVector<f64>; 4] {
+        array::from_fn(|_| {
+            BoundedVector::with_shared_bounds(
+                -10.0,
+                10.0,
+                (0..5).map(|_| rng.gen_range(-1.0..1.0)).collect(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_global_recombination() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parents = parents(&mut rng);
+        let parent_refs = parents.each_ref();
+
+        let children: [_; 4] = GlobalIntermediate.recombine(&mut rng, &parent_refs);
+        let mean = parents.iter().map(|x| x.value[0]).sum::<f64>() / 4.0;
+        assert!(children
+            .iter()
+            .all(|child| (child.value[0] - mean).abs() < 1e-12));
+
+        let children: [_; 4] = GlobalDiscrete.recombine(&mut rng, &parent_refs);
+        assert!(children.iter().all(|child| child
+            .value
+            .iter()
+            .enumerate()
+            .all(|(i, x)| parents.iter().any(|parent| parent.value[i] == *x))));
+    }
+
+    #[test]
+    fn test_rotation_angle_circular_mean() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let step_sizes = BoundedVector::with_shared_bounds(0.0, 1.0, vec![0.5; 2]);
+        let parent_1 =
+            CorrelatedStrategyParameter::new(step_sizes.clone(), vec![std::f64::consts::PI - 0.1]);
+        let parent_2 =
+            CorrelatedStrategyParameter::new(step_sizes, vec![0.1 - std::f64::consts::PI]);
+
+        // The arithmetic mean 0 would be the opposite rotation
+        let [child] = GlobalIntermediate.recombine(&mut rng, &[&parent_1, &parent_2]);
+        assert!((child.rotation_angles[0].abs() - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_multi_parent_centroid() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parents = parents(&mut rng);
+        let parent_refs = parents.each_ref();
+        let center = centroid(&to_vectors(&parent_refs));
+        let primary_center = centroid(&to_vectors(&parent_refs)[..3]);
+
+        // The mean of many children approaches the centroid of the (primary) parents
+        let mean_child = |children: Vec<BoundedVector<f64>>| {
+            let count = children.len() as f64;
+            children
+                .into_iter()
+                .map(|child| DVector::from_vec(child.value))
+                .sum::<DVector<f64>>()
+                / count
+        };
+
+        let undx = UnimodalNormalDistribution::new();
+        let children = (0..5000)
+            .flat_map(|_| -> [_; 2] { undx.recombine(&mut rng, &parent_refs) })
+            .collect();
+        assert!((mean_child(children) - &primary_center).norm() < 0.05);
+
+        let spx = Simplex::new(f64::sqrt(5.0));
+        let children = (0..5000)
+            .flat_map(|_| -> [_; 2] { spx.recombine(&mut rng, &parent_refs) })
+            .collect();
+        assert!((mean_child(children) - &center).norm() < 0.05);
+
+        let pcx = ParentCentric::default();
+        let children: Vec<_> = (0..5000)
+            .map(|_| pcx.recombine(&mut rng, &parent_refs))
+            .map(|[child]: [_; 1]| child)
+            .collect();
+        let parent = DVector::from_column_slice(&parents[0].value);
+        assert!((mean_child(children) - parent).norm() < 0.05);
+    }
+
+    #[test]
+    fn test_multi_parent_boundary_handler() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parents: [_; 3] = array::from_fn(|i| {
+            BoundedVector::with_shared_bounds(-1.0, 1.0, vec![0.9 - 0.05 * i as f64, -0.9])
+        });
+        let parent_refs = parents.each_ref();
+        let feasible = |children: &[BoundedVector<f64>]| {
+            children.iter().all(|child| {
+                // Reflection leaves the faces of the box unoccupied
+                child.value.iter().all(|x| x.abs() < 1.0)
+            })
+        };
+
+        let undx = UnimodalNormalDistribution::with_deviations(20.0, 20.0)
+            .with_boundary_handler(crate::boundary::Reflect);
+        let spx = Simplex::new(20.0).with_boundary_handler(crate::boundary::Reflect);
+        let pcx = ParentCentric::new(20.0, 20.0).with_boundary_handler(crate::boundary::Reflect);
+        for _ in 0..100 {
+            let children: [_; 3] = undx.recombine(&mut rng, &parent_refs);
+            assert!(feasible(&children));
+            let children: [_; 3] = spx.recombine(&mut rng, &parent_refs);
+            assert!(feasible(&children));
+            let children: [_; 3] = pcx.recombine(&mut rng, &parent_refs);
+            assert!(feasible(&children));
+        }
+
+        // Resampling keeps children within the bounds as well
+        let resample = Simplex::new(20.0).with_boundary_handler(crate::boundary::Resample::new(5));
+        let children: [_; 3] = resample.recombine(&mut rng, &parent_refs);
+        assert!(children
+            .iter()
+            .all(|child| child.value.iter().all(|x| x.abs() <= 1.0)));
+    }
+}
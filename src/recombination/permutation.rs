@@ -0,0 +1,302 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::Recombinator;
+use crate::parameter::Permutation;
+
+/// Partially Mapped Crossover (PMX).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartiallyMapped;
+
+/// Order crossover (OX), preserving the relative order of the second parent.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order;
+
+/// Cycle crossover, taking every element from the same position in either parent.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cycle;
+
+/// Edge recombination, building children from the adjacencies present in the parents.
+///
+/// Permutations are considered cyclic, so the last element is adjacent to the first.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeRecombination;
+
+/// Inclusive bounds of a random segment.
+fn sample_segment<R: Rng + ?Sized>(rng: &mut R, length: usize) -> (usize, usize) {
+    let a = rng.gen_range(0..length);
+    let b = rng.gen_range(0..length);
+    (usize::min(a, b), usize::max(a, b))
+}
+
+fn positions(permutation: &Permutation) -> Vec<usize> {
+    let mut positions = vec![0; permutation.len()];
+    for (i, &x) in permutation.value.iter().enumerate() {
+        positions[x] = i;
+    }
+    positions
+}
+
+fn check_lengths(parent_1: &Permutation, parent_2: &Permutation) {
+    assert_eq!(
+        parent_1.len(),
+        parent_2.len(),
+        "The parents should be permutations of equal length"
+    );
+}
+
+fn partially_mapped_child(
+    parent_1: &Permutation,
+    parent_2: &Permutation,
+    start: usize,
+    end: usize,
+) -> Permutation {
+    let parent_1_positions = positions(parent_1);
+    let mut child = parent_2.clone();
+
+    child.value[start..=end].copy_from_slice(&parent_1.value[start..=end]);
+    for i in (0..start).chain(end + 1..parent_1.len()) {
+        let mut x = parent_2.value[i];
+        // Follow the mapping between the segments until the element is not in the copied segment
+        while (start..=end).contains(&parent_1_positions[x]) {
+            x = parent_2.value[parent_1_positions[x]];
+        }
+        child.value[i] = x;
+    }
+
+    child
+}
+
+fn order_child(
+    parent_1: &Permutation,
+    parent_2: &Permutation,
+    start: usize,
+    end: usize,
+) -> Permutation {
+    let length = parent_1.len();
+    let mut used = vec![false; length];
+    let mut child = parent_1.clone();
+
+    parent_1.value[start..=end]
+        .iter()
+        .for_each(|&x| used[x] = true);
+
+    let remaining = (1..=length)
+        .map(|offset| parent_2.value[(end + offset) % length])
+        .filter(|&x| !used[x]);
+    let free_positions = (1..=length)
+        .map(|offset| (end + offset) % length)
+        .filter(|i| !(start..=end).contains(i));
+
+    free_positions
+        .zip(remaining)
+        .for_each(|(i, x)| child.value[i] = x);
+
+    child
+}
+
+impl Recombinator<Permutation, 2> for PartiallyMapped {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&Permutation; 2],
+    ) -> [Permutation; 2] {
+        let [parent_1, parent_2] = parents;
+        check_lengths(parent_1, parent_2);
+
+        let (start, end) = sample_segment(rng, parent_1.len());
+
+        [
+            partially_mapped_child(parent_1, parent_2, start, end),
+            partially_mapped_child(parent_2, parent_1, start, end),
+        ]
+    }
+}
+
+impl Recombinator<Permutation, 2> for Order {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&Permutation; 2],
+    ) -> [Permutation; 2] {
+        let [parent_1, parent_2] = parents;
+        check_lengths(parent_1, parent_2);
+
+        let (start, end) = sample_segment(rng, parent_1.len());
+
+        [
+            order_child(parent_1, parent_2, start, end),
+            order_child(parent_2, parent_1, start, end),
+        ]
+    }
+}
+
+impl Recombinator<Permutation, 2> for Cycle {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        _rng: &mut R,
+        parents: &[&Permutation; 2],
+    ) -> [Permutation; 2] {
+        let [parent_1, parent_2] = parents;
+        check_lengths(parent_1, parent_2);
+
+        let parent_1_positions = positions(parent_1);
+        let mut child_1 = Permutation::clone(parent_1);
+        let mut child_2 = Permutation::clone(parent_2);
+        let mut visited = vec![false; parent_1.len()];
+
+        // Alternate cycles are taken from the other parent
+        let mut swap_cycle = false;
+        for start in 0..parent_1.len() {
+            if visited[start] {
+                continue;
+            }
+
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                if swap_cycle {
+                    child_1.value[i] = parent_2.value[i];
+                    child_2.value[i] = parent_1.value[i];
+                }
+                i = parent_1_positions[parent_2.value[i]];
+            }
+            swap_cycle = !swap_cycle;
+        }
+
+        [child_1, child_2]
+    }
+}
+
+impl EdgeRecombination {
+    fn edge_table(parents: &[&Permutation; 2]) -> Vec<Vec<usize>> {
+        let length = parents[0].len();
+        let mut table = vec![Vec::with_capacity(4); length];
+
+        for parent in parents {
+            for (i, &x) in parent.value.iter().enumerate() {
+                table[x].push(parent.value[(i + length - 1) % length]);
+                table[x].push(parent.value[(i + 1) % length]);
+            }
+        }
+
+        table
+    }
+
+    fn build_child<R: Rng + ?Sized>(
+        rng: &mut R,
+        mut table: Vec<Vec<usize>>,
+        first: usize,
+    ) -> Permutation {
+        let length = table.len();
+        let mut used = vec![false; length];
+        let mut value = Vec::with_capacity(length);
+
+        let mut current = first;
+        loop {
+            value.push(current);
+            used[current] = true;
+            table
+                .iter_mut()
+                .for_each(|edges| edges.retain(|&x| x != current));
+
+            if value.len() == length {
+                break;
+            }
+
+            let edges = &table[current];
+            // Edges present in both parents appear twice and are preferred
+            let common: Vec<_> = edges
+                .iter()
+                .copied()
+                .filter(|x| edges.iter().filter(|&y| y == x).count() > 1)
+                .collect();
+
+            let next = if !common.is_empty() {
+                common.choose(rng).copied()
+            } else {
+                let edge_count = |x: usize| {
+                    let mut edges = table[x].clone();
+                    edges.sort_unstable();
+                    edges.dedup();
+                    edges.len()
+                };
+                let shortest = edges.iter().map(|&x| edge_count(x)).min();
+                let candidates: Vec<_> = edges
+                    .iter()
+                    .copied()
+                    .filter(|&x| Some(edge_count(x)) == shortest)
+                    .collect();
+                candidates.choose(rng).copied()
+            };
+
+            current = next.unwrap_or_else(|| {
+                let unused: Vec<_> = (0..length).filter(|&x| !used[x]).collect();
+                *unused.choose(rng).unwrap()
+            });
+        }
+
+        Permutation { value }
+    }
+}
+
+impl Recombinator<Permutation, 2> for EdgeRecombination {
+    fn recombine<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        parents: &[&Permutation; 2],
+    ) -> [Permutation; 2] {
+        let [parent_1, parent_2] = parents;
+        check_lengths(parent_1, parent_2);
+
+        if parent_1.is_empty() {
+            return [Permutation::clone(parent_1), Permutation::clone(parent_2)];
+        }
+
+        let table = Self::edge_table(parents);
+
+        [
+            Self::build_child(rng, table.clone(), parent_1.value[0]),
+            Self::build_child(rng, table, parent_2.value[0]),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_recombinators_preserve_permutation() {
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        for _ in 0..100 {
+            let parent_1 = Permutation::random(&mut rng, 10);
+            let parent_2 = Permutation::random(&mut rng, 10);
+            let parents = [&parent_1, &parent_2];
+
+            let children = [
+                PartiallyMapped.recombine(&mut rng, &parents),
+                Order.recombine(&mut rng, &parents),
+                Cycle.recombine(&mut rng, &parents),
+                EdgeRecombination.recombine(&mut rng, &parents),
+            ];
+
+            for child in children.into_iter().flatten() {
+                Permutation::new(child.value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let parent_1 = Permutation::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let parent_2 = Permutation::new(vec![8, 2, 6, 7, 1, 5, 4, 0, 3]);
+
+        let [child_1, child_2] = Cycle.recombine(&mut rng, &[&parent_1, &parent_2]);
+        assert_eq!(child_1.value, vec![0, 2, 6, 3, 1, 5, 4, 7, 8]);
+        assert_eq!(child_2.value, vec![8, 1, 2, 7, 4, 5, 6, 0, 3]);
+    }
+}
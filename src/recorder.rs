@@ -0,0 +1,238 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::{individual::Individual, observer::Observer, population::Population, solver::RunState};
+
+const CSV_HEADER: &str =
+    "generation,evaluations,elapsed,best,mean,median,worst,std,mean_age,diversity";
+
+type DiversityMeasure<I, F> = Box<dyn Fn(&Population<I, F>) -> f64>;
+
+/// Summary of the fitness and age distribution of a population at a given point of a run.
+#[derive(Clone, Debug)]
+pub struct GenerationStatistics {
+    pub generation: usize,
+    pub evaluations: usize,
+    pub elapsed: Duration,
+    pub best: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub worst: f64,
+    pub std: f64,
+    pub mean_age: f64,
+    pub diversity: Option<f64>,
+}
+
+impl GenerationStatistics {
+    pub fn new<I, F>(
+        population: &Population<I, F>,
+        state: &RunState,
+        diversity: Option<f64>,
+    ) -> Self
+    where
+        I: Individual<F>,
+        F: PartialOrd + Clone + Into<f64>,
+    {
+        let size = population.len() as f64;
+        let mut fitnesses: Vec<f64> = population
+            .individuals()
+            .iter()
+            .map(|individual| individual.fitness().into())
+            .collect();
+        fitnesses.sort_by(f64::total_cmp);
+
+        let mean = fitnesses.iter().sum::<f64>() / size;
+        let variance = fitnesses.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / size;
+        let middle = fitnesses.len() / 2;
+        let median = match fitnesses.len() {
+            0 => f64::NAN,
+            length if length % 2 == 0 => (fitnesses[middle - 1] + fitnesses[middle]) / 2.0,
+            _ => fitnesses[middle],
+        };
+        let mean_age = population
+            .individuals()
+            .iter()
+            .map(|individual| individual.age() as f64)
+            .sum::<f64>()
+            / size;
+
+        Self {
+            generation: state.generation,
+            evaluations: state.evaluations,
+            elapsed: state.elapsed,
+            best: fitnesses.last().copied().unwrap_or(f64::NAN),
+            mean,
+            median,
+            worst: fitnesses.first().copied().unwrap_or(f64::NAN),
+            std: variance.sqrt(),
+            mean_age,
+            diversity,
+        }
+    }
+
+    fn to_csv_record(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.generation,
+            self.evaluations,
+            self.elapsed.as_secs_f64(),
+            self.best,
+            self.mean,
+            self.median,
+            self.worst,
+            self.std,
+            self.mean_age,
+            self.diversity.map(|x| x.to_string()).unwrap_or_default(),
+        )
+    }
+
+    fn to_json(&self) -> String {
+        // JSON has no representation for non-finite numbers
+        let number = |x: f64| match x.is_finite() {
+            true => x.to_string(),
+            false => String::from("null"),
+        };
+
+        format!(
+            "{{\"generation\":{},\"evaluations\":{},\"elapsed\":{},\"best\":{},\"mean\":{},\"median\":{},\"worst\":{},\"std\":{},\"mean_age\":{},\"diversity\":{}}}",
+            self.generation,
+            self.evaluations,
+            number(self.elapsed.as_secs_f64()),
+            number(self.best),
+            number(self.mean),
+            number(self.median),
+            number(self.worst),
+            number(self.std),
+            number(self.mean_age),
+            self.diversity.map(number).unwrap_or_else(|| String::from("null")),
+        )
+    }
+}
+
+/// Observer recording the statistics of the population after every generation.
+pub struct HistoryRecorder<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    diversity: Option<DiversityMeasure<I, F>>,
+    history: Vec<GenerationStatistics>,
+}
+
+impl<I, F> HistoryRecorder<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            diversity: None,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn with_diversity<D>(diversity: D) -> Self
+    where
+        D: Fn(&Population<I, F>) -> f64 + 'static,
+    {
+        Self {
+            diversity: Some(Box::new(diversity)),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn history(&self) -> &[GenerationStatistics] {
+        &self.history
+    }
+
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for statistics in self.history.iter() {
+            writeln!(writer, "{}", statistics.to_csv_record())?;
+        }
+        writer.flush()
+    }
+
+    pub fn write_json_lines<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for statistics in self.history.iter() {
+            writeln!(writer, "{}", statistics.to_json())?;
+        }
+        writer.flush()
+    }
+}
+
+impl<I, F> HistoryRecorder<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone + Into<f64>,
+{
+    pub fn record(&mut self, population: &Population<I, F>, state: &RunState) {
+        let diversity = self.diversity.as_ref().map(|measure| measure(population));
+        self.history
+            .push(GenerationStatistics::new(population, state, diversity));
+    }
+}
+
+impl<I, F> Default for HistoryRecorder<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, F> Observer<I, F> for HistoryRecorder<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone + Into<f64>,
+{
+    fn on_initialization(&mut self, population: &Population<I, F>, state: &RunState) {
+        self.record(population, state);
+    }
+
+    fn on_survivor_selection(&mut self, population: &Population<I, F>, state: &RunState) {
+        self.record(population, state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::individual::BoundedVectorIndividual;
+
+    #[test]
+    fn test_recorder_export() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut population: Population<BoundedVectorIndividual<f64, f64>, f64> =
+            Population::new(&mut rng, -1.0, 1.0, 3, 4);
+        population.set_fitnesses(&[1.0, 4.0, 2.0, 3.0]);
+
+        let mut recorder = HistoryRecorder::with_diversity(Population::diversity);
+        recorder.record(&population, &RunState::default());
+
+        let statistics = &recorder.history()[0];
+        assert_eq!(statistics.best, 4.0);
+        assert_eq!(statistics.worst, 1.0);
+        assert_eq!(statistics.mean, 2.5);
+        assert_eq!(statistics.median, 2.5);
+        assert!(statistics.diversity.is_some());
+
+        let mut csv = Vec::new();
+        recorder.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert_eq!(csv.lines().next().unwrap(), CSV_HEADER);
+
+        let mut json_lines = Vec::new();
+        recorder.write_json_lines(&mut json_lines).unwrap();
+        let json_lines = String::from_utf8(json_lines).unwrap();
+        assert_eq!(json_lines.lines().count(), 1);
+        assert!(json_lines.starts_with("{\"generation\":0,"));
+    }
+}
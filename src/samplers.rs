@@ -1,8 +1,24 @@
 use itertools::Itertools;
-use nalgebra::{Cholesky, DMatrix, DVector};
+use nalgebra::{Cholesky, DMatrix, DVector, SymmetricEigen};
 use rand::Rng;
 use rand_distr::Normal;
 
+/// Matrix `A` with `A A^T` equal to the covariance, from its Cholesky decomposition when it is
+/// positive definite.
+///
+/// Otherwise negative eigenvalues caused by rounding errors are clipped to zero and `A = B D^(1/2)`
+/// is used, from the eigendecomposition `B D B^T` of the covariance.
+fn covariance_factor(covariance: DMatrix<f64>) -> DMatrix<f64> {
+    match Cholesky::new(covariance.clone()) {
+        Some(cholesky) => cholesky.unpack(),
+        None => {
+            let eigen = SymmetricEigen::new(covariance);
+            let sqrt_eigenvalues = eigen.eigenvalues.map(|x| x.max(0.0).sqrt());
+            eigen.eigenvectors * DMatrix::from_diagonal(&sqrt_eigenvalues)
+        }
+    }
+}
+
 pub fn sample_multivariate_gaussian<R: Rng + ?Sized, B: FromIterator<C>, C: FromIterator<f64>>(
     rng: &mut R,
     mean: &[f64],
@@ -21,24 +37,68 @@ pub fn sample_multivariate_gaussian<R: Rng + ?Sized, B: FromIterator<C>, C: From
         .map(|chunk| chunk.collect_vec())
         .collect();
 
-    let cholesky =
-        Cholesky::new(DMatrix::from_row_slice(mean.len(), mean.len(), covariance)).unwrap();
+    let factor = covariance_factor(DMatrix::from_row_slice(mean.len(), mean.len(), covariance));
     let mu = DVector::from(mean.to_vec());
 
     normal_samples
         .into_iter()
         .map(DVector::from)
-        .map(|z| &mu + &cholesky.l() * z)
+        .map(|z| &mu + &factor * z)
         .map(|multivariate_sample| multivariate_sample.iter().copied().collect())
         .collect()
 }
 
+/// Rotation composed of the elementary rotations in the plane of every pair of axes `i < j`, in
+/// lexicographic order of `(i, j)`.
+pub fn rotation_matrix(dimension: usize, rotation_angles: &[f64]) -> DMatrix<f64> {
+    if rotation_angles.len() != dimension * dimension.saturating_sub(1) / 2 {
+        panic!("Dimension and rotation angles do not have compatible sizes");
+    }
+
+    let mut rotation = DMatrix::identity(dimension, dimension);
+    let planes = (0..dimension).tuple_combinations::<(_, _)>();
+    for ((i, j), angle) in planes.zip(rotation_angles) {
+        let (sin, cos) = angle.sin_cos();
+        let column_i = rotation.column(i).clone_owned();
+        let column_j = rotation.column(j).clone_owned();
+        rotation.set_column(i, &(&column_i * cos + &column_j * sin));
+        rotation.set_column(j, &(&column_j * cos - &column_i * sin));
+    }
+    rotation
+}
+
+/// Samples a Gaussian with the given standard deviations along the axes, rotated by the given
+/// angles as in [`rotation_matrix`].
+pub fn sample_rotated_gaussian<R: Rng + ?Sized, C: FromIterator<f64>>(
+    rng: &mut R,
+    mean: &[f64],
+    step_sizes: &[f64],
+    rotation_angles: &[f64],
+) -> C {
+    if mean.len() != step_sizes.len() {
+        panic!("Mean and step sizes do not have compatible sizes");
+    }
+
+    let z = DVector::from_iterator(
+        mean.len(),
+        step_sizes
+            .iter()
+            .map(|std| std * rng.sample::<f64, _>(Normal::new(0.0, 1.0).unwrap())),
+    );
+    let mu = DVector::from(mean.to_vec());
+
+    (mu + rotation_matrix(mean.len(), rotation_angles) * z)
+        .iter()
+        .copied()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use nalgebra::{DMatrix, Dyn, Matrix, VecStorage};
+    use nalgebra::{DMatrix, DVector, Dyn, Matrix, VecStorage};
     use rand::{rngs::StdRng, Rng, SeedableRng};
 
-    use super::sample_multivariate_gaussian;
+    use super::{rotation_matrix, sample_multivariate_gaussian, sample_rotated_gaussian};
 
     const TOLERANCE: f64 = 1e-2;
     const N_SAMPLES: usize = 100_000;
@@ -124,4 +184,56 @@ mod tests {
 
         test_gaussian_sampler_convergence(&mut rng, &mean, &covariance, N_SAMPLES, TOLERANCE);
     }
+
+    #[test]
+    fn test_singular_gaussian_statistics() {
+        let mean = [1.0, 1.0, 1.0];
+        let covariance = [1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 2.0];
+        let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+        test_gaussian_sampler_convergence(&mut rng, &mean, &covariance, N_SAMPLES, TOLERANCE);
+    }
+
+    #[test]
+    fn test_rotated_gaussian_statistics() {
+        let mean = [1.0, -1.0, 0.0];
+        let step_sizes = [2.0, 0.5, 1.0];
+        let rotation_angles = [0.5, -1.0, 2.0];
+        let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+        let rotation = rotation_matrix(mean.len(), &rotation_angles);
+        assert!(
+            (&rotation * rotation.transpose() - DMatrix::identity(3, 3))
+                .abs()
+                .max()
+                < 1e-12
+        );
+
+        let variances = DMatrix::from_diagonal(&DVector::from_iterator(
+            3,
+            step_sizes.iter().map(|std| std * std),
+        ));
+        let covariance = &rotation * variances * rotation.transpose();
+
+        let samples: Vec<f64> = (0..N_SAMPLES)
+            .flat_map(|_| {
+                sample_rotated_gaussian::<_, Vec<f64>>(
+                    &mut rng,
+                    &mean,
+                    &step_sizes,
+                    &rotation_angles,
+                )
+            })
+            .collect();
+        let sample_matrix = DMatrix::from_row_iterator(N_SAMPLES, mean.len(), samples);
+        let sample_covariance = calculate_sample_covariance(sample_matrix);
+
+        let covariance_error = (&sample_covariance - &covariance).abs().mean();
+        assert!(
+            covariance_error < 2.0 * TOLERANCE,
+            "Resulting covariance error: {} < {}",
+            covariance_error,
+            2.0 * TOLERANCE
+        );
+    }
 }
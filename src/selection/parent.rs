@@ -2,7 +2,10 @@ use itertools::Itertools;
 use rand::{seq::index, Rng};
 use rand_distr::{Bernoulli, Uniform, WeightedIndex};
 
-use crate::individual::Individual;
+use crate::{
+    individual::Individual,
+    multi_objective::{crowded_compare, crowded_ranking, MultiObjectiveFitness},
+};
 
 pub trait ParentSelector<F>
 where
@@ -15,6 +18,7 @@ where
         C: FromIterator<&'a I>;
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformSelector;
 
 impl UniformSelector {
@@ -48,6 +52,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FitnessProportionate;
 
 impl ParentSelector<f64> for FitnessProportionate {
@@ -86,6 +91,7 @@ impl ParentSelector<f64> for FitnessProportionate {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearRanking {
     s: f64,
 }
@@ -129,6 +135,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExponentialRanking;
 
 impl<F> ParentSelector<F> for ExponentialRanking
@@ -169,11 +176,13 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentSampleMethod {
     WithReplacement,
     WithoutReplacement,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tournament {
     tournament_size: usize,
     sample_method: TournamentSampleMethod,
@@ -289,6 +298,45 @@ where
     }
 }
 
+/// Binary tournament of NSGA-II using the crowded-comparison operator.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrowdedTournament;
+
+impl CrowdedTournament {
+    pub fn new() -> Self {
+        CrowdedTournament
+    }
+}
+
+impl Default for CrowdedTournament {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentSelector<MultiObjectiveFitness> for CrowdedTournament {
+    fn select<'a, R, I, C>(&self, rng: &mut R, individuals: &'a [I], number_children: usize) -> C
+    where
+        R: Rng + ?Sized,
+        I: Individual<MultiObjectiveFitness>,
+        C: FromIterator<&'a I>,
+    {
+        let fitnesses: Vec<_> = individuals.iter().map(|x| x.fitness()).collect();
+        let ranking = crowded_ranking(&fitnesses);
+        let length = individuals.len();
+
+        (0..number_children)
+            .map(|_| {
+                let (a, b) = (rng.gen_range(0..length), rng.gen_range(0..length));
+                match crowded_compare(&ranking[a], &ranking[b]).is_ge() {
+                    true => &individuals[a],
+                    false => &individuals[b],
+                }
+            })
+            .collect()
+    }
+}
+
 pub fn roulette_wheel<'a, R, I, F, C>(
     rng: &mut R,
     individuals: &'a [I],
@@ -2,14 +2,19 @@ use std::{cmp::Ordering, collections::HashSet, mem::swap};
 
 use rand::{seq::IteratorRandom, Rng};
 
-use crate::individual::Individual;
+use crate::{
+    individual::Individual,
+    multi_objective::{crowding_distance, fast_non_dominated_sort, MultiObjectiveFitness},
+};
 
-pub trait SurvivorSelector {
-    fn select<R, I, F>(&self, rng: &mut R, population: &mut [I], offspring: Vec<I>)
+pub trait SurvivorSelector<F>
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, rng: &mut R, population: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
-        I: Individual<F>,
-        F: PartialOrd;
+        I: Individual<F>;
 }
 
 #[derive(PartialEq)]
@@ -61,6 +66,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReplaceWorstSelector {
     replacement_rate: f64,
 }
@@ -73,12 +79,14 @@ impl ReplaceWorstSelector {
     }
 }
 
-impl SurvivorSelector for ReplaceWorstSelector {
-    fn select<R, I, F>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for ReplaceWorstSelector
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         // Consume and make given offspring value mutable
         let mut offspring = offspring;
@@ -101,16 +109,19 @@ impl SurvivorSelector for ReplaceWorstSelector {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoundRobinTournament {
     number_rivals: usize,
 }
 
-impl SurvivorSelector for RoundRobinTournament {
-    fn select<R, I, F>(&self, rng: &mut R, population: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for RoundRobinTournament
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, rng: &mut R, population: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         let mut offspring = offspring;
 
@@ -181,14 +192,17 @@ impl SurvivorSelector for RoundRobinTournament {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MergeRanked;
 
-impl SurvivorSelector for MergeRanked {
-    fn select<R, I, F>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for MergeRanked
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         let mut offspring = offspring;
 
@@ -199,7 +213,7 @@ impl SurvivorSelector for MergeRanked {
         let mut candidates: Vec<_> = population
             .iter()
             .enumerate()
-            .map(|(i, x)| (i, GroupType::Offspring, x.fitness()))
+            .map(|(i, x)| (i, GroupType::Population, x.fitness()))
             .chain(offspring_candidates)
             .collect();
 
@@ -234,14 +248,17 @@ impl SurvivorSelector for MergeRanked {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenerationalRanked;
 
-impl SurvivorSelector for GenerationalRanked {
-    fn select<R, I, F>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
+impl<F> SurvivorSelector<F> for GenerationalRanked
+where
+    F: PartialOrd,
+{
+    fn select<R, I>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
     where
         R: Rng + ?Sized,
         I: Individual<F>,
-        F: PartialOrd,
     {
         let population_size = population.len();
         let offspring_size = offspring.len();
@@ -260,3 +277,113 @@ impl SurvivorSelector for GenerationalRanked {
             .for_each(|(a, b)| swap(a, b));
     }
 }
+
+/// Elitist non-dominated sorting survivor selection of NSGA-II.
+///
+/// Population and offspring are merged and survivors are taken front by front, the last front
+/// that does not fit entirely is truncated by crowding distance.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nsga2;
+
+impl Nsga2 {
+    pub fn new() -> Self {
+        Nsga2
+    }
+}
+
+impl Default for Nsga2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SurvivorSelector<MultiObjectiveFitness> for Nsga2 {
+    fn select<R, I>(&self, _rng: &mut R, population: &mut [I], offspring: Vec<I>)
+    where
+        R: Rng + ?Sized,
+        I: Individual<MultiObjectiveFitness>,
+    {
+        let mut offspring = offspring;
+        let population_size = population.len();
+
+        let fitnesses: Vec<_> = population
+            .iter()
+            .chain(offspring.iter())
+            .map(|x| x.fitness())
+            .collect();
+
+        let mut survivors = Vec::with_capacity(population_size);
+        for front in fast_non_dominated_sort(&fitnesses) {
+            let remaining = population_size - survivors.len();
+            if front.len() <= remaining {
+                survivors.extend(front);
+            } else {
+                let distances = crowding_distance(&fitnesses, &front);
+                let mut order: Vec<_> = (0..front.len()).collect();
+                order.sort_by(|&a, &b| distances[b].total_cmp(&distances[a]));
+                survivors.extend(order.into_iter().take(remaining).map(|k| front[k]));
+            }
+
+            if survivors.len() == population_size {
+                break;
+            }
+        }
+
+        let (population_winners, offspring_winners): (HashSet<_>, HashSet<_>) =
+            survivors.into_iter().partition(|&i| i < population_size);
+
+        let offspring_winner_refs = offspring
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| offspring_winners.contains(&(i + population_size)))
+            .map(|(_, x)| x);
+
+        let population_loser_refs = population
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| !population_winners.contains(i))
+            .map(|(_, x)| x);
+
+        // Insert survivors into population
+        population_loser_refs
+            .zip(offspring_winner_refs)
+            .for_each(|(a, b)| swap(a, b));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{individual::BoundedVectorIndividual, parameter::BoundedVector};
+
+    fn individuals(fitnesses: &[f64]) -> Vec<BoundedVectorIndividual<f64, f64>> {
+        fitnesses
+            .iter()
+            .map(|&fitness| {
+                let mut individual = BoundedVectorIndividual::new(BoundedVector::unbounded(vec![]));
+                individual.set_fitness(fitness);
+                individual
+            })
+            .collect()
+    }
+
+    fn fitnesses(individuals: &[BoundedVectorIndividual<f64, f64>]) -> Vec<f64> {
+        let mut fitnesses: Vec<_> = individuals.iter().map(|x| x.fitness()).collect();
+        fitnesses.sort_by(|a, b| b.total_cmp(a));
+        fitnesses
+    }
+
+    #[test]
+    fn test_merge_ranked() {
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        let mut population = individuals(&[5.0, 1.0, 3.0]);
+        MergeRanked.select(&mut rng, &mut population, individuals(&[0.0, -1.0]));
+        assert_eq!(fitnesses(&population), vec![5.0, 3.0, 1.0]);
+
+        MergeRanked.select(&mut rng, &mut population, individuals(&[4.0, 0.0]));
+        assert_eq!(fitnesses(&population), vec![5.0, 4.0, 3.0]);
+    }
+}
@@ -0,0 +1,251 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::{
+    evaluation::Evaluator,
+    individual::Individual,
+    mutation::Mutator,
+    observer::Observer,
+    population::Population,
+    recombination::Recombinator,
+    selection::{parent::ParentSelector, survivor::SurvivorSelector},
+    termination::Termination,
+};
+
+/// Counters describing the progress of a run.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunState {
+    pub generation: usize,
+    pub evaluations: usize,
+    pub elapsed: Duration,
+}
+
+impl RunState {
+    pub(crate) fn lap(&mut self, lap: &mut Instant) {
+        self.elapsed += lap.elapsed();
+        *lap = Instant::now();
+    }
+}
+
+/// Generational evolutionary algorithm composed from the operator traits of this crate.
+///
+/// Every recombination combines `N` parents into `K` children.
+pub struct EvolutionaryAlgorithm<PS, C, M, SS, E, const N: usize, const K: usize = N> {
+    parent_selector: PS,
+    recombinator: C,
+    mutator: M,
+    survivor_selector: SS,
+    evaluator: E,
+    number_offspring: usize,
+}
+
+impl<PS, C, M, SS, E, const N: usize, const K: usize> EvolutionaryAlgorithm<PS, C, M, SS, E, N, K> {
+    pub fn new(
+        parent_selector: PS,
+        recombinator: C,
+        mutator: M,
+        survivor_selector: SS,
+        evaluator: E,
+        number_offspring: usize,
+    ) -> Self {
+        Self {
+            parent_selector,
+            recombinator,
+            mutator,
+            survivor_selector,
+            evaluator,
+            number_offspring,
+        }
+    }
+
+    pub fn number_offspring(&self) -> usize {
+        self.number_offspring
+    }
+
+    pub fn evaluate<I, F>(&self, individuals: &mut [I])
+    where
+        I: Individual<F>,
+        F: PartialOrd,
+        E: Evaluator<I, F>,
+    {
+        self.evaluator.evaluate(individuals);
+    }
+
+    pub fn initialize<I, F>(&self, population: &mut Population<I, F>)
+    where
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        E: Evaluator<I, F>,
+    {
+        self.evaluate(population.individuals_mut());
+    }
+
+    pub fn select_parents<'a, R, I, F>(
+        &self,
+        rng: &mut R,
+        population: &'a Population<I, F>,
+    ) -> Vec<&'a I>
+    where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        PS: ParentSelector<F>,
+    {
+        // Select enough parents to fill the last recombination, excess offspring is discarded
+        let number_parents = self.number_offspring.div_ceil(K) * N;
+        self.parent_selector
+            .select(rng, population.individuals(), number_parents)
+    }
+
+    pub fn generate_offspring<R, I, F>(&self, rng: &mut R, mating_pool: &[&I]) -> Vec<I>
+    where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+    {
+        let mut offspring: Vec<_> = mating_pool
+            .chunks_exact(N)
+            .flat_map(|parents| {
+                self.recombinator
+                    .recombine(rng, parents.try_into().unwrap())
+            })
+            .take(self.number_offspring)
+            .collect();
+
+        offspring.iter_mut().for_each(|individual| {
+            self.mutator.mutate(rng, individual);
+            individual.set_age(0);
+        });
+
+        offspring
+    }
+
+    pub fn breed<R, I, F>(&self, rng: &mut R, population: &Population<I, F>) -> Vec<I>
+    where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        PS: ParentSelector<F>,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+    {
+        let mating_pool = self.select_parents(rng, population);
+        self.generate_offspring(rng, &mating_pool)
+    }
+
+    pub fn select_survivors<R, I, F>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<I, F>,
+        offspring: Vec<I>,
+    ) where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        SS: SurvivorSelector<F>,
+    {
+        population.increment_ages();
+        self.survivor_selector
+            .select(rng, population.individuals_mut(), offspring);
+    }
+
+    pub fn step<R, I, F, O>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<I, F>,
+        state: &mut RunState,
+        observer: &mut O,
+    ) where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        PS: ParentSelector<F>,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+        SS: SurvivorSelector<F>,
+        E: Evaluator<I, F>,
+        O: Observer<I, F>,
+    {
+        let mut lap = Instant::now();
+
+        let mating_pool = self.select_parents(rng, population);
+        state.lap(&mut lap);
+        observer.on_parent_selection(population, &mating_pool, state);
+
+        let mut offspring = self.generate_offspring(rng, &mating_pool);
+        self.evaluate(&mut offspring);
+        state.evaluations += offspring.len();
+        state.lap(&mut lap);
+        observer.on_offspring_evaluation(population, &offspring, state);
+
+        self.select_survivors(rng, population, offspring);
+        state.generation += 1;
+        state.lap(&mut lap);
+        observer.on_survivor_selection(population, state);
+    }
+
+    pub fn run<R, I, F, T, O>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<I, F>,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> RunState
+    where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        PS: ParentSelector<F>,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+        SS: SurvivorSelector<F>,
+        E: Evaluator<I, F>,
+        T: Termination<I, F>,
+        O: Observer<I, F>,
+    {
+        let mut lap = Instant::now();
+        let mut state = RunState::default();
+
+        self.initialize(population);
+        state.evaluations += population.len();
+        state.lap(&mut lap);
+        observer.on_initialization(population, &state);
+
+        self.resume(rng, population, state, termination, observer)
+    }
+
+    /// Continues a run from an evaluated population, e.g. one restored from a checkpoint.
+    pub fn resume<R, I, F, T, O>(
+        &self,
+        rng: &mut R,
+        population: &mut Population<I, F>,
+        state: RunState,
+        termination: &mut T,
+        observer: &mut O,
+    ) -> RunState
+    where
+        R: Rng + ?Sized,
+        I: Individual<F>,
+        F: PartialOrd + Clone,
+        PS: ParentSelector<F>,
+        C: Recombinator<I, N, K>,
+        M: Mutator<I>,
+        SS: SurvivorSelector<F>,
+        E: Evaluator<I, F>,
+        T: Termination<I, F>,
+        O: Observer<I, F>,
+    {
+        let mut state = state;
+
+        while !termination.is_terminated(population, &state) && !observer.should_stop() {
+            self.step(rng, population, &mut state, observer);
+        }
+
+        observer.on_termination(population, &state);
+        state
+    }
+}
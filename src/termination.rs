@@ -0,0 +1,266 @@
+use std::time::Duration;
+
+use crate::{individual::Individual, population::Population, solver::RunState};
+
+/// Decides after every generation whether a run should be stopped.
+pub trait Termination<I, F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, state: &RunState) -> bool;
+}
+
+impl<I, F, T> Termination<I, F> for Box<T>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    T: Termination<I, F> + ?Sized,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, state: &RunState) -> bool {
+        T::is_terminated(self, population, state)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxGenerations {
+    max_generations: usize,
+}
+
+impl MaxGenerations {
+    pub fn new(max_generations: usize) -> Self {
+        Self { max_generations }
+    }
+}
+
+impl<I, F> Termination<I, F> for MaxGenerations
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, _population: &Population<I, F>, state: &RunState) -> bool {
+        state.generation >= self.max_generations
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxEvaluations {
+    max_evaluations: usize,
+}
+
+impl MaxEvaluations {
+    pub fn new(max_evaluations: usize) -> Self {
+        Self { max_evaluations }
+    }
+}
+
+impl<I, F> Termination<I, F> for MaxEvaluations
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, _population: &Population<I, F>, state: &RunState) -> bool {
+        state.evaluations >= self.max_evaluations
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeBudget {
+    budget: Duration,
+}
+
+impl TimeBudget {
+    pub fn new(budget: Duration) -> Self {
+        Self { budget }
+    }
+}
+
+impl<I, F> Termination<I, F> for TimeBudget
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, _population: &Population<I, F>, state: &RunState) -> bool {
+        state.elapsed >= self.budget
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetFitness<F> {
+    target: F,
+}
+
+impl<F> TargetFitness<F> {
+    pub fn new(target: F) -> Self {
+        Self { target }
+    }
+}
+
+impl<I, F> Termination<I, F> for TargetFitness<F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, _state: &RunState) -> bool {
+        population
+            .individuals()
+            .iter()
+            .any(|individual| individual.fitness() >= self.target)
+    }
+}
+
+/// Terminates when the best fitness has not improved for a number of consecutive generations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stagnation<F> {
+    generations: usize,
+    stagnant_generations: usize,
+    best_fitness: Option<F>,
+}
+
+impl<F> Stagnation<F> {
+    pub fn new(generations: usize) -> Self {
+        Self {
+            generations,
+            stagnant_generations: 0,
+            best_fitness: None,
+        }
+    }
+}
+
+impl<I, F> Termination<I, F> for Stagnation<F>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, _state: &RunState) -> bool {
+        let fitness = match population.best() {
+            Some(individual) => individual.fitness(),
+            None => return false,
+        };
+
+        match &self.best_fitness {
+            Some(best_fitness) if fitness <= *best_fitness => self.stagnant_generations += 1,
+            _ => {
+                self.best_fitness = Some(fitness);
+                self.stagnant_generations = 0;
+            }
+        }
+
+        self.stagnant_generations >= self.generations
+    }
+}
+
+/// Terminates when the diversity measure of the population drops below a threshold.
+pub struct DiversityThreshold<D> {
+    measure: D,
+    threshold: f64,
+}
+
+impl<D> DiversityThreshold<D> {
+    pub fn new(measure: D, threshold: f64) -> Self {
+        Self { measure, threshold }
+    }
+}
+
+impl<I, F, D> Termination<I, F> for DiversityThreshold<D>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    D: Fn(&Population<I, F>) -> f64,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, _state: &RunState) -> bool {
+        (self.measure)(population) < self.threshold
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Any<A, B> {
+    first: A,
+    second: B,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct All<A, B> {
+    first: A,
+    second: B,
+}
+
+pub fn any<A, B>(first: A, second: B) -> Any<A, B> {
+    Any { first, second }
+}
+
+pub fn all<A, B>(first: A, second: B) -> All<A, B> {
+    All { first, second }
+}
+
+impl<I, F, A, B> Termination<I, F> for Any<A, B>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    A: Termination<I, F>,
+    B: Termination<I, F>,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, state: &RunState) -> bool {
+        // Both criteria are evaluated so stateful criteria observe every generation
+        let first = self.first.is_terminated(population, state);
+        let second = self.second.is_terminated(population, state);
+        first || second
+    }
+}
+
+impl<I, F, A, B> Termination<I, F> for All<A, B>
+where
+    I: Individual<F>,
+    F: PartialOrd + Clone,
+    A: Termination<I, F>,
+    B: Termination<I, F>,
+{
+    fn is_terminated(&mut self, population: &Population<I, F>, state: &RunState) -> bool {
+        let first = self.first.is_terminated(population, state);
+        let second = self.second.is_terminated(population, state);
+        first && second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::individual::BoundedVectorIndividual;
+
+    fn population() -> Population<BoundedVectorIndividual<f64, f64>, f64> {
+        let mut rng = StdRng::seed_from_u64(1234);
+        Population::new(&mut rng, -1.0, 1.0, 3, 10)
+    }
+
+    #[test]
+    fn test_stagnation() {
+        let population = population();
+        let state = RunState::default();
+        let mut termination = Stagnation::new(2);
+
+        assert!(!termination.is_terminated(&population, &state));
+        assert!(!termination.is_terminated(&population, &state));
+        assert!(termination.is_terminated(&population, &state));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let population = population();
+        let state = RunState {
+            generation: 10,
+            evaluations: 100,
+            ..Default::default()
+        };
+
+        assert!(any(MaxGenerations::new(10), MaxEvaluations::new(1000))
+            .is_terminated(&population, &state));
+        assert!(!all(MaxGenerations::new(10), MaxEvaluations::new(1000))
+            .is_terminated(&population, &state));
+        assert!(
+            DiversityThreshold::new(Population::diversity, f64::INFINITY)
+                .is_terminated(&population, &state)
+        );
+    }
+}
@@ -1,17 +1,36 @@
 use metaheurustics::{
     benchmark::bent_cigar,
-    individual::{BoundedVectorIndividualMutator, BoundedVectorIndividualRecombinator, Individual},
-    mutation::{Mutator, UniformMutator},
+    individual::{
+        BoundedVectorIndividual, BoundedVectorIndividualMutator,
+        BoundedVectorIndividualRecombinator, GenomeIndividual, GenomeIndividualMutator,
+        GenomeIndividualRecombinator, Individual,
+    },
+    multi_objective::{fast_non_dominated_sort, MultiObjectiveFitness},
+    mutation::{
+        integer::{GenewiseCreep, GenewiseRandomResetting},
+        Mutator, SelfAdaptiveGaussianVectorMutator, UncorrelatedLogNormal, UniformMutator,
+    },
+    observer::Observer,
+    parameter::{BoundedVector, SelfAdaptiveGaussianVector},
     population::Population,
-    recombination::{Recombinator, SingleArithmetic},
+    recombination::{
+        differential::{DifferentialCrossover, DifferentialMutation, DifferentialRecombinator},
+        integer,
+        multi_parent::GlobalIntermediate,
+        Discrete, Recombinator, SelfAdaptiveGaussianVectorRecombinator, SingleArithmetic,
+        SingleChild,
+    },
     selection::{
-        parent::{ParentSelector, UniformSelector},
-        survivor::{ReplaceWorstSelector, SurvivorSelector},
+        parent::{CrowdedTournament, ParentSelector, UniformSelector},
+        survivor::{MergeRanked, Nsga2, ReplaceWorstSelector, SurvivorSelector},
     },
+    solver::{EvolutionaryAlgorithm, RunState},
+    termination::MaxGenerations,
 };
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 #[test]
+#[allow(clippy::neg_multiply)]
 fn test_improvement_per_epoch() {
     let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
     let evaluation_func = |x: &[f64]| -1.0 * bent_cigar(x);
@@ -76,3 +95,297 @@ fn test_improvement_per_epoch() {
         last_max_fitness = max_fitness;
     }
 }
+
+#[test]
+fn test_evolutionary_algorithm_odd_offspring() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        BoundedVectorIndividualRecombinator::new(SingleArithmetic::new(0.5)),
+        BoundedVectorIndividualMutator::new(UniformMutator::new(0.01)),
+        ReplaceWorstSelector::new(0.5),
+        |individual: &BoundedVectorIndividual<f64, f64>| -bent_cigar(&individual.vector().value),
+        51,
+    );
+
+    let mut population = Population::new(&mut rng, -100.0, 100.0, 10, 100);
+    algorithm.initialize(&mut population);
+    let initial_max_fitness = population
+        .individuals()
+        .iter()
+        .map(|individual| individual.fitness())
+        .max_by(|a, b| a.total_cmp(b))
+        .unwrap();
+
+    let offspring = algorithm.breed(&mut rng, &population);
+    assert_eq!(offspring.len(), 51);
+
+    let state = algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(50),
+        &mut (),
+    );
+    assert_eq!(state.generation, 50);
+    assert_eq!(state.evaluations, 100 + 50 * 51);
+
+    let max_fitness = population
+        .individuals()
+        .iter()
+        .map(|individual| individual.fitness())
+        .max_by(|a, b| a.total_cmp(b))
+        .unwrap();
+
+    assert!(max_fitness >= initial_max_fitness);
+    assert!(population
+        .individuals()
+        .iter()
+        .any(|individual| individual.age() > 0));
+}
+
+struct StopAfter {
+    generations: usize,
+    observed_generations: usize,
+    terminated: bool,
+}
+
+impl Observer<BoundedVectorIndividual<f64, f64>, f64> for StopAfter {
+    fn on_survivor_selection(
+        &mut self,
+        population: &Population<BoundedVectorIndividual<f64, f64>, f64>,
+        state: &RunState,
+    ) {
+        assert_eq!(population.len(), 20);
+        self.observed_generations = state.generation;
+    }
+
+    fn on_termination(
+        &mut self,
+        _population: &Population<BoundedVectorIndividual<f64, f64>, f64>,
+        _state: &RunState,
+    ) {
+        self.terminated = true;
+    }
+
+    fn should_stop(&self) -> bool {
+        self.observed_generations >= self.generations
+    }
+}
+
+#[test]
+fn test_observer_early_stopping() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        BoundedVectorIndividualRecombinator::new(SingleArithmetic::new(0.5)),
+        BoundedVectorIndividualMutator::new(UniformMutator::new(0.01)),
+        ReplaceWorstSelector::new(0.5),
+        |individual: &BoundedVectorIndividual<f64, f64>| -bent_cigar(&individual.vector().value),
+        10,
+    );
+
+    let mut population = Population::new(&mut rng, -100.0, 100.0, 10, 20);
+    let mut observer = StopAfter {
+        generations: 5,
+        observed_generations: 0,
+        terminated: false,
+    };
+    let state = algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(50),
+        &mut observer,
+    );
+
+    assert_eq!(state.generation, 5);
+    assert!(observer.terminated);
+}
+
+#[test]
+fn test_nsga2_schaffer() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+    let schaffer = |individual: &BoundedVectorIndividual<f64, MultiObjectiveFitness>| {
+        let x = individual.vector().value[0];
+        MultiObjectiveFitness::new(vec![-x * x, -(x - 2.0) * (x - 2.0)])
+    };
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        CrowdedTournament::new(),
+        BoundedVectorIndividualRecombinator::new(SingleArithmetic::new(0.5)),
+        BoundedVectorIndividualMutator::new(UniformMutator::new(0.1)),
+        Nsga2::new(),
+        schaffer,
+        40,
+    );
+
+    let mut population = Population::new(&mut rng, -10.0, 10.0, 1, 40);
+    algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(50),
+        &mut (),
+    );
+
+    let fitnesses: Vec<_> = population
+        .individuals()
+        .iter()
+        .map(|individual| individual.fitness())
+        .collect();
+    assert_eq!(fast_non_dominated_sort(&fitnesses).len(), 1);
+    assert!(population
+        .individuals()
+        .iter()
+        .all(|individual| (-0.1..=2.1).contains(&individual.vector().value[0])));
+}
+
+#[test]
+fn test_integer_vector_evolution() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+    let distance = |individual: &BoundedVectorIndividual<i64, f64>| {
+        -(individual
+            .vector()
+            .value
+            .iter()
+            .map(|x| (x - 3).abs())
+            .sum::<i64>() as f64)
+    };
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        BoundedVectorIndividualRecombinator::new(integer::Uniform),
+        BoundedVectorIndividualMutator::new(GenewiseCreep::new(0.2, 1)),
+        ReplaceWorstSelector::new(0.5),
+        distance,
+        20,
+    );
+
+    let mut population: Population<_, f64> = Population::new(&mut rng, -20, 20, 8, 40);
+    let mutator = BoundedVectorIndividualMutator::new(GenewiseRandomResetting::new(0.1));
+    population
+        .individuals_mut()
+        .iter_mut()
+        .for_each(|individual| {
+            mutator.mutate(&mut rng, individual);
+        });
+
+    algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(100),
+        &mut (),
+    );
+    assert_eq!(population.best().unwrap().fitness(), 0.0);
+}
+
+#[test]
+fn test_single_child_recombination() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        BoundedVectorIndividualRecombinator::new(SingleChild::new(SingleArithmetic::new(0.5))),
+        BoundedVectorIndividualMutator::new(UniformMutator::new(0.01)),
+        ReplaceWorstSelector::new(0.5),
+        |individual: &BoundedVectorIndividual<f64, f64>| -bent_cigar(&individual.vector().value),
+        7,
+    );
+
+    let mut population = Population::new(&mut rng, -100.0, 100.0, 10, 20);
+    algorithm.initialize(&mut population);
+    assert_eq!(algorithm.select_parents(&mut rng, &population).len(), 14);
+    assert_eq!(algorithm.breed(&mut rng, &population).len(), 7);
+
+    let state = algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(10),
+        &mut (),
+    );
+    assert_eq!(state.evaluations, 20 + 10 * 7);
+}
+
+#[test]
+fn test_self_adaptive_genome_evolution() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+    let sphere = |individual: &GenomeIndividual<
+        SelfAdaptiveGaussianVector<f64, BoundedVector<f64>>,
+        f64,
+    >| {
+        -individual
+            .genome()
+            .value
+            .value
+            .iter()
+            .map(|x| x * x)
+            .sum::<f64>()
+    };
+
+    let algorithm = EvolutionaryAlgorithm::new(
+        UniformSelector::new(),
+        GenomeIndividualRecombinator::new(SelfAdaptiveGaussianVectorRecombinator::new(
+            Discrete,
+            GlobalIntermediate,
+        )),
+        GenomeIndividualMutator::new(SelfAdaptiveGaussianVectorMutator::new(
+            UncorrelatedLogNormal::with_dimension(5, 1e-6),
+        )),
+        ReplaceWorstSelector::new(0.5),
+        sphere,
+        20,
+    );
+
+    let individuals = (0..20)
+        .map(|_| {
+            GenomeIndividual::new(SelfAdaptiveGaussianVector {
+                value: BoundedVector::with_shared_bounds(
+                    -10.0,
+                    10.0,
+                    (0..5).map(|_| rng.gen_range(-10.0..10.0)).collect(),
+                ),
+                strategy_parameter: BoundedVector::with_shared_bounds(0.0, 5.0, vec![1.0; 5]),
+            })
+        })
+        .collect();
+    let mut population = Population::new_from_individuals(individuals);
+
+    algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(200),
+        &mut (),
+    );
+    assert!(population.best().unwrap().fitness() > -1e-3);
+}
+
+#[test]
+fn test_differential_recombination() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1234);
+
+    let algorithm = EvolutionaryAlgorithm::<_, _, _, _, _, 4, 1>::new(
+        UniformSelector::new(),
+        BoundedVectorIndividualRecombinator::new(DifferentialRecombinator::new(
+            DifferentialMutation::Rand1,
+            DifferentialCrossover::Binomial,
+            0.5,
+            0.9,
+        )),
+        BoundedVectorIndividualMutator::new(UniformMutator::new(0.0)),
+        MergeRanked,
+        |individual: &BoundedVectorIndividual<f64, f64>| {
+            -individual.vector().value.iter().map(|x| x * x).sum::<f64>()
+        },
+        20,
+    );
+
+    let mut population = Population::new(&mut rng, -5.0, 5.0, 5, 20);
+    algorithm.run(
+        &mut rng,
+        &mut population,
+        &mut MaxGenerations::new(200),
+        &mut (),
+    );
+    let fitness = population.best().unwrap().fitness();
+    assert!(fitness > -1e-3, "Best fitness: {}", fitness);
+}